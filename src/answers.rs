@@ -0,0 +1,307 @@
+//! Expected answers, read from a small subset of TOML:
+//!
+//! ```toml
+//! # a comment
+//! [[answer]]
+//! day = 5
+//! input = "day5_example.dat"
+//! part1 = "CMZ"
+//! part2 = "MCD"
+//! ```
+//!
+//! The answers file and the inputs it names are in the `data` directory of this repository,
+//! `$AOC_DATA_DIR` only moves the inputs of the other commands.
+
+use std::fmt::Display;
+
+use crate::{
+    error::Failure,
+    input::{repository_data_dir, Source},
+    registry::{Entry, SOLVERS},
+    Error, Result,
+};
+
+/// File holding the expected answers, in the data directory
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Expected answers of one day for one input file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Expected {
+    pub day: u8,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    Integer(u64),
+    Str(String),
+}
+
+pub fn parse(s: &str) -> Result<Vec<Expected>> {
+    let mut answers: Vec<Expected> = vec![];
+    for line in s.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line == "[[answer]]" {
+            answers.push(Expected::default());
+            continue;
+        }
+        let locate = |at, message: String| Failure::new(at, message).locate(s);
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(locate(line, "expected `key = value`".to_string()));
+        };
+        let Some(current) = answers.last_mut() else {
+            return Err(locate(line, "key outside of an [[answer]]".to_string()));
+        };
+        let value = value.trim();
+        let parsed = parse_value(value).map_err(|e| locate(value, e))?;
+        match (key.trim(), parsed) {
+            ("day", Value::Integer(day)) => {
+                current.day = u8::try_from(day)
+                    .map_err(|_| locate(value, format!("day {} out of range", day)))?
+            }
+            ("input", Value::Str(input)) => current.input = input,
+            ("part1", Value::Str(answer)) => current.part1 = Some(answer),
+            ("part2", Value::Str(answer)) => current.part2 = Some(answer),
+            (key, parsed) => {
+                return Err(locate(
+                    line,
+                    format!("unexpected key or type `{} = {:?}`", key, parsed),
+                ))
+            }
+        }
+    }
+    match answers
+        .iter()
+        .position(|a| a.day == 0 || a.input.is_empty())
+    {
+        Some(idx) => Err(Error::unsolvable(format!(
+            "answer #{} needs a day and an input",
+            idx + 1
+        ))),
+        None => Ok(answers),
+    }
+}
+
+fn parse_value(s: &str) -> std::result::Result<Value, String> {
+    if let Ok(integer) = s.parse::<u64>() {
+        return Ok(Value::Integer(integer));
+    }
+    let Some(quoted) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+        return Err(format!("`{}` is neither an integer nor a string", s));
+    };
+    let mut string = String::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('"') => string.push('"'),
+                Some('\\') => string.push('\\'),
+                Some('n') => string.push('\n'),
+                other => return Err(format!("unsupported escape \\{:?}", other)),
+            },
+            '"' => return Err("unescaped quote in string".to_string()),
+            c => string.push(c),
+        }
+    }
+    Ok(Value::Str(string))
+}
+
+/// Outcome of one solver run against an expected answer
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The solver couldn't produce an answer
+    Error(String),
+    Missing(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub input: Option<String>,
+    pub status: Status,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let input = self.input.as_deref().unwrap_or("-");
+        match &self.status {
+            Status::Pass => write!(
+                f,
+                "pass     day {} part {} ({})",
+                self.day, self.part, input
+            ),
+            Status::Fail { expected, actual } => write!(
+                f,
+                "FAIL     day {} part {} ({}): expected {}, got {}",
+                self.day, self.part, input, expected, actual
+            ),
+            Status::Error(e) => write!(
+                f,
+                "ERROR    day {} part {} ({}): {}",
+                self.day, self.part, input, e
+            ),
+            Status::Missing(why) => write!(
+                f,
+                "missing  day {} part {} ({}): {}",
+                self.day, self.part, input, why
+            ),
+        }
+    }
+}
+
+/// Run every registered solver against each of its expected answers
+pub fn verify(answers: &[Expected]) -> Vec<Check> {
+    SOLVERS
+        .iter()
+        .flat_map(|entry| verify_entry(entry, answers))
+        .collect()
+}
+
+fn verify_entry(entry: &Entry, answers: &[Expected]) -> Vec<Check> {
+    let checks: Vec<Check> = answers
+        .iter()
+        .filter(|expected| expected.day == entry.day)
+        .filter_map(|expected| {
+            let answer = expected.answer(entry.part)?;
+            let source = Source::File(repository_data_dir().join(&expected.input));
+            let status = match source.read() {
+                Ok(input) => match (entry.solve)(&input) {
+                    Ok(actual) if actual == answer => Status::Pass,
+                    Ok(actual) => Status::Fail {
+                        expected: answer.to_string(),
+                        actual,
+                    },
+                    Err(e) => Status::Error(e.to_string()),
+                },
+                Err(e) => Status::Missing(format!("cannot read {}: {}", source, e)),
+            };
+            Some(Check {
+                day: entry.day,
+                part: entry.part,
+                input: Some(expected.input.clone()),
+                status,
+            })
+        })
+        .collect();
+    if checks.is_empty() {
+        vec![Check {
+            day: entry.day,
+            part: entry.part,
+            input: None,
+            status: Status::Missing("no expected answer".to_string()),
+        }]
+    } else {
+        checks
+    }
+}
+
+/// Expected answers checked in the data directory
+pub fn load() -> Result<Vec<Expected>> {
+    let path = repository_data_dir().join(ANSWERS_FILE);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| Error::Io(format!("{}: {}", path.display(), e)))?;
+    parse(&content)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, verify, Expected, Status};
+    use crate::Error;
+
+    #[test]
+    fn parse_answers() {
+        const INPUT: &str = r#"
+# comment
+[[answer]]
+day = 5
+input = "day5_example.dat"
+part1 = "CMZ"
+
+[[answer]]
+day = 6
+input = "day6.dat"
+part2 = "say \"hi\""
+"#;
+        assert_eq!(
+            parse(INPUT).unwrap(),
+            vec![
+                Expected {
+                    day: 5,
+                    input: "day5_example.dat".to_string(),
+                    part1: Some("CMZ".to_string()),
+                    part2: None,
+                },
+                Expected {
+                    day: 6,
+                    input: "day6.dat".to_string(),
+                    part1: None,
+                    part2: Some("say \"hi\"".to_string()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors_report_the_line() {
+        assert_eq!(
+            parse("day = 1").unwrap_err(),
+            Error::parse(1, 1, "key outside of an [[answer]]")
+        );
+        assert_eq!(
+            parse("[[answer]]\nday = one").unwrap_err(),
+            Error::parse(2, 7, "`one` is neither an integer nor a string")
+        );
+        assert_eq!(
+            parse("[[answer]]\nday = 1").unwrap_err(),
+            Error::unsolvable("answer #1 needs a day and an input")
+        );
+    }
+
+    #[test]
+    fn verify_reports_pass_fail_and_missing() {
+        let answers = vec![Expected {
+            day: 5,
+            input: "day5_example.dat".to_string(),
+            part1: Some("CMZ".to_string()),
+            part2: Some("CMZ".to_string()),
+        }];
+        let checks = verify(&answers);
+        let status_of = |day, part| {
+            &checks
+                .iter()
+                .find(|c| c.day == day && c.part == part)
+                .unwrap()
+                .status
+        };
+        assert_eq!(status_of(5, 1), &Status::Pass);
+        assert_eq!(
+            status_of(5, 2),
+            &Status::Fail {
+                expected: "CMZ".to_string(),
+                actual: "MCD".to_string()
+            }
+        );
+        assert!(matches!(status_of(1, 1), Status::Missing(_)));
+    }
+}
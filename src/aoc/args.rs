@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+/// Every option known by a command
+const OPTIONS: &[&str] = &[
+    "input",
+    "part",
+    "iterations",
+    "format",
+    "color",
+    "size",
+    "seed",
+    "rules",
+];
+
+/// Command line split into positional arguments and `--name value` options
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    pub positionals: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    if !OPTIONS.contains(&name) {
+                        return Err(format!("unknown option --{}", name));
+                    }
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("missing value for option --{}", name))?;
+                    if parsed.options.insert(name.to_string(), value).is_some() {
+                        return Err(format!("option --{} given more than once", name));
+                    }
+                }
+                None => parsed.positionals.push(arg),
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// Fail on the first option, in alphabetical order, that isn't in `allowed`
+    pub fn only(&self, allowed: &[&str]) -> Result<(), String> {
+        let mut names: Vec<&String> = self.options.keys().collect();
+        names.sort();
+        match names
+            .into_iter()
+            .find(|name| !allowed.contains(&name.as_str()))
+        {
+            Some(name) => Err(format!("option --{} isn't supported by this command", name)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Args;
+
+    fn parse(line: &str) -> Result<Args, String> {
+        Args::parse(line.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn options_can_be_anywhere() {
+        let args = parse("run --input - 1 2").unwrap();
+        assert_eq!(args.positionals, vec!["run", "1", "2"]);
+        assert_eq!(args.option("input"), Some("-"));
+        assert_eq!(args.option("other"), None);
+    }
+
+    #[test]
+    fn options_are_given_once() {
+        assert_eq!(
+            parse("run 2 --part 1 --part 2"),
+            Err("option --part given more than once".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_options_are_rejected() {
+        assert_eq!(
+            parse("run 5 --inptu nowhere"),
+            Err("unknown option --inptu".to_string())
+        );
+        let args = parse("run 5 --size 10 --input -").unwrap();
+        assert_eq!(args.only(&["input", "size"]), Ok(()));
+        assert_eq!(
+            args.only(&["input", "part"]),
+            Err("option --size isn't supported by this command".to_string())
+        );
+    }
+
+    #[test]
+    fn options_need_a_value() {
+        assert!(parse("run 1 2 --input").is_err());
+    }
+}
@@ -0,0 +1,403 @@
+mod args;
+
+use std::{
+    io::{BufRead, IsTerminal},
+    process::ExitCode,
+};
+
+use advent_of_code::{
+    answers::{self, Status},
+    bench,
+    day2::{self, Rules},
+    input::Source,
+    registry::{self, SOLVERS},
+    report::{Format, Style},
+    rng::Rng,
+};
+use args::Args;
+
+const USAGE: &str = "usage:
+    aoc run <day> [<part>] [--part <part>] [--input <path>] [--rules <path>]
+                                             solve both parts of a day, or only the given part
+    aoc list                                 list every registered day and part
+    aoc bench <day> [--input <path>] [--iterations <n>] [--format text|json]
+                                             time parsing, both parts and the hot paths of a day
+    aoc verify                               check every solver against data/answers.toml
+    aoc report <day> [--input <path>] [--format text|json] [--rules <path>]
+                                             statistics about the puzzle input of a day
+    aoc visualise <day> [--input <path>] [--color auto|always|never]
+                                             draw the puzzle input of a day, with colours in a
+                                             terminal or when --color is always
+    aoc generate <day> [--size <bytes>] [--seed <n>]
+                                             print a random puzzle input of about <bytes> bytes
+                                             (default: 1000000 bytes, seed 2022)
+
+options:
+    --input <path>    read the puzzle input from <path>, or from stdin when <path> is `-`
+                      (default: $AOC_DATA_DIR/day<day>.dat, AOC_DATA_DIR defaulting to the data/ directory of this repository)
+    --rules <path>    play day 2 with the cyclic game of a rules file, such as day2_rpsls.rules";
+
+fn main() -> ExitCode {
+    simple_logger::SimpleLogger::new()
+        .with_level(log::LevelFilter::Warn)
+        .env()
+        .init()
+        .unwrap();
+
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let positionals: Vec<&str> = args.positionals.iter().map(String::as_str).collect();
+    let allowed: &[&str] = match positionals.first() {
+        Some(&"run") => &["input", "part", "rules"],
+        Some(&"bench") => &["input", "iterations", "format"],
+        Some(&"report") => &["input", "format", "rules"],
+        Some(&"visualise") => &["input", "color"],
+        Some(&"generate") => &["size", "seed"],
+        _ => &[],
+    };
+    if let Err(e) = args.only(allowed) {
+        eprintln!("{}\n{}", e, USAGE);
+        return ExitCode::FAILURE;
+    }
+    match positionals.as_slice() {
+        ["run", day] => run(day, args.option("part"), &args),
+        ["run", _, _] if args.option("part").is_some() => {
+            eprintln!(
+                "give the part either as <part> or as --part, not both\n{}",
+                USAGE
+            );
+            ExitCode::FAILURE
+        }
+        ["run", day, part] => run(day, Some(part), &args),
+        ["bench", day] => bench(day, &args),
+        ["verify"] => verify(),
+        ["report", day] => report(day, &args),
+        ["visualise", day] => visualise(day, &args),
+        ["generate", day] => generate(day, &args),
+        ["list"] => {
+            SOLVERS
+                .iter()
+                .for_each(|e| println!("{} {}", e.day, e.part));
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(day: &str, part: Option<&str>, args: &Args) -> ExitCode {
+    let Ok(day) = day.parse::<u8>() else {
+        eprintln!("day must be a number\n{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+    let entry = match part.map(str::parse::<u8>) {
+        None => None,
+        Some(Ok(part)) => match registry::find(day, part) {
+            Some(entry) => Some(entry),
+            None => {
+                eprintln!("no solver registered for day {} part {}", day, part);
+                return ExitCode::FAILURE;
+            }
+        },
+        Some(Err(_)) => {
+            eprintln!("part must be a number\n{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(registered) = registry::find_day(day) else {
+        eprintln!("no solver registered for day {}", day);
+        return ExitCode::FAILURE;
+    };
+    if let Some(path) = args.option("rules") {
+        return run_with_rules(day, entry.map(|e| e.part), path, args);
+    }
+    let Some(mut input) = open_input(day, args) else {
+        return ExitCode::FAILURE;
+    };
+    if let Some(entry) = entry {
+        // a single part is printed alone, so that it can be piped
+        return match (entry.read)(&mut input) {
+            Ok(answer) => {
+                println!("{}", answer);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("day {} part {}: {}", day, entry.part, e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+    // the input is parsed once, a parse error is reported once for the whole day
+    let answers = match (registered.solve)(&mut input) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("day {}: {}", day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    print_answers(day, answers)
+}
+
+fn print_answers<A: std::fmt::Display>(
+    day: u8,
+    answers: Vec<Result<A, impl std::fmt::Display>>,
+) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    for (part, answer) in (1..).zip(answers) {
+        match answer {
+            Ok(answer) => println!("part {}: {}", part, answer),
+            Err(e) => {
+                eprintln!("day {} part {}: {}", day, part, e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Day 2 played with the cyclic game of the rules file at `path`
+fn run_with_rules(day: u8, part: Option<u8>, path: &str, args: &Args) -> ExitCode {
+    let Some(rules) = load_rules(day, path) else {
+        return ExitCode::FAILURE;
+    };
+    let Some(input) = read_input(day, args) else {
+        return ExitCode::FAILURE;
+    };
+    let mut answers = match day2::play(&input, &rules) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("day {}: {}", day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(part) = part else {
+        return print_answers(day, answers);
+    };
+    match answers.swap_remove(part as usize - 1) {
+        Ok(answer) => {
+            println!("{}", answer);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("day {} part {}: {}", day, part, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The rules given with `--rules`, only day 2 can be played with other rules
+fn load_rules(day: u8, path: &str) -> Option<Rules> {
+    if day != 2 {
+        eprintln!("--rules only applies to day 2\n{}", USAGE);
+        return None;
+    }
+    let source = Source::from_arg(path);
+    let text = source
+        .read()
+        .map_err(|e| eprintln!("failed to read {}: {}", source, e))
+        .ok()?;
+    Rules::parse(&text)
+        .map_err(|e| eprintln!("{}: {}", source, e))
+        .ok()
+}
+
+fn source(day: u8, args: &Args) -> Source {
+    match args.option("input") {
+        Some(path) => Source::from_arg(path),
+        None => Source::default_for(day),
+    }
+}
+
+fn read_input(day: u8, args: &Args) -> Option<String> {
+    let source = source(day, args);
+    source
+        .read()
+        .map_err(|e| eprintln!("failed to read {}: {}", source, e))
+        .ok()
+}
+
+/// The input as a reader, for the commands that let the day stream it
+fn open_input(day: u8, args: &Args) -> Option<Box<dyn BufRead>> {
+    let source = source(day, args);
+    source
+        .open()
+        .map_err(|e| eprintln!("failed to read {}: {}", source, e))
+        .ok()
+}
+
+fn bench(day: &str, args: &Args) -> ExitCode {
+    let Some(day) = day.parse::<u8>().ok().and_then(registry::find_day) else {
+        eprintln!("no day {} registered", day);
+        return ExitCode::FAILURE;
+    };
+    let Ok(iterations) = args.option("iterations").unwrap_or("100").parse::<usize>() else {
+        eprintln!("--iterations must be a number\n{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+    let format = match args.option("format").unwrap_or("text").parse::<Format>() {
+        Ok(format) => format,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(input) = read_input(day.day, args) else {
+        return ExitCode::FAILURE;
+    };
+    let stats = match (day.bench)(&input, iterations) {
+        Ok(stats) => stats,
+        Err(e) => {
+            eprintln!("day {}: {}", day.day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match format {
+        Format::Text => {
+            // measure runs every step at least once, whatever was asked
+            println!(
+                "day {}: {} iterations over {} bytes",
+                day.day,
+                stats.first().map_or(iterations, |s| s.iterations),
+                input.len()
+            );
+            stats.iter().for_each(|s| println!("  {}", s));
+        }
+        Format::Json => println!("{}", bench::to_json(day.day, &stats)),
+    }
+    ExitCode::SUCCESS
+}
+
+fn verify() -> ExitCode {
+    let expected = match answers::load() {
+        Ok(expected) => expected,
+        Err(e) => {
+            eprintln!("{}: {}", answers::ANSWERS_FILE, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let checks = answers::verify(&expected);
+    checks.iter().for_each(|check| println!("{}", check));
+
+    let count = |f: fn(&Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+    let failed = count(|s| matches!(s, Status::Fail { .. } | Status::Error(_)));
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(|s| *s == Status::Pass),
+        failed,
+        count(|s| matches!(s, Status::Missing(_)))
+    );
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn report(day: &str, args: &Args) -> ExitCode {
+    let Some(day) = day.parse::<u8>().ok().and_then(registry::find_day) else {
+        eprintln!("no day {} registered", day);
+        return ExitCode::FAILURE;
+    };
+    let Some(report) = day.report else {
+        eprintln!("no report for day {}", day.day);
+        return ExitCode::FAILURE;
+    };
+    let format = match args.option("format").unwrap_or("text").parse::<Format>() {
+        Ok(format) => format,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let report = match args.option("rules") {
+        Some(path) => {
+            let Some(rules) = load_rules(day.day, path) else {
+                return ExitCode::FAILURE;
+            };
+            let Some(input) = read_input(day.day, args) else {
+                return ExitCode::FAILURE;
+            };
+            day2::report_with(&input, &rules, format)
+        }
+        None => {
+            let Some(mut input) = open_input(day.day, args) else {
+                return ExitCode::FAILURE;
+            };
+            report(&mut input, format)
+        }
+    };
+    match report {
+        Ok(report) => {
+            println!("{}", report.trim_end());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("day {}: {}", day.day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn visualise(day: &str, args: &Args) -> ExitCode {
+    let Some(day) = day.parse::<u8>().ok().and_then(registry::find_day) else {
+        eprintln!("no day {} registered", day);
+        return ExitCode::FAILURE;
+    };
+    let Some(visualise) = day.visualise else {
+        eprintln!("no visualisation for day {}", day.day);
+        return ExitCode::FAILURE;
+    };
+    let style = match args.option("color").unwrap_or("auto") {
+        "always" => Style::Ansi,
+        "never" => Style::Plain,
+        "auto" if std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none() => {
+            Style::Ansi
+        }
+        "auto" => Style::Plain,
+        other => {
+            eprintln!("unknown color mode {}\n{}", other, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(input) = read_input(day.day, args) else {
+        return ExitCode::FAILURE;
+    };
+    match visualise(&input, style) {
+        Ok(drawing) => {
+            print!("{}", drawing);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("day {}: {}", day.day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(day: &str, args: &Args) -> ExitCode {
+    let Some(day) = day.parse::<u8>().ok().and_then(registry::find_day) else {
+        eprintln!("no day {} registered", day);
+        return ExitCode::FAILURE;
+    };
+    let Some(generate) = day.generate else {
+        eprintln!("no generator for day {}", day.day);
+        return ExitCode::FAILURE;
+    };
+    let Ok(size) = args.option("size").unwrap_or("1000000").parse::<usize>() else {
+        eprintln!("--size must be a number\n{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+    let Ok(seed) = args.option("seed").unwrap_or("2022").parse::<u64>() else {
+        eprintln!("--seed must be a number\n{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+    print!("{}", generate(&mut Rng::new(seed), size));
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,167 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{Result, Solution};
+
+/// Wall time of the repeated runs of one step (parsing, a part or a kernel)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub name: String,
+    pub iterations: usize,
+    pub bytes: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Bytes of input processed per second, based on the median time
+    pub fn throughput(&self) -> f64 {
+        if self.median.is_zero() {
+            return f64::INFINITY;
+        }
+        self.bytes as f64 / self.median.as_secs_f64()
+    }
+
+    fn to_json(&self) -> String {
+        let throughput = match self.throughput() {
+            t if t.is_finite() => format!("{:.0}", t),
+            _ => "null".to_string(),
+        };
+        format!(
+            "{{\"name\":\"{}\",\"iterations\":{},\"bytes\":{},\"min_ns\":{},\"median_ns\":{},\"max_ns\":{},\"bytes_per_sec\":{}}}",
+            self.name,
+            self.iterations,
+            self.bytes,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos(),
+            throughput
+        )
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:32} min {:>12?}  median {:>12?}  max {:>12?}  {:>10.2} MB/s",
+            self.name,
+            self.min,
+            self.median,
+            self.max,
+            self.throughput() / 1_000_000.0
+        )
+    }
+}
+
+/// Run `f` `iterations` times (at least once) and collect its timings
+pub fn measure<R>(name: &str, bytes: usize, iterations: usize, mut f: impl FnMut() -> R) -> Stats {
+    let iterations = iterations.max(1);
+    let mut timings: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    timings.sort();
+    Stats {
+        name: name.to_string(),
+        iterations,
+        bytes,
+        min: timings[0],
+        // the mean of the two middle timings for an even number of runs
+        median: (timings[(iterations - 1) / 2] + timings[iterations / 2]) / 2,
+        max: timings[iterations - 1],
+    }
+}
+
+/// Time the parsing, both parts and the kernels of a solution
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Vec<Stats>> {
+    let bytes = input.len();
+    let parsed = S::parse(input)?;
+    let mut stats = vec![
+        measure("parse", bytes, iterations, || S::parse(black_box(input))),
+        measure("part1", bytes, iterations, || S::part1(black_box(&parsed))),
+        measure("part2", bytes, iterations, || S::part2(black_box(&parsed))),
+    ];
+    stats.extend(S::kernels().into_iter().map(|(name, kernel)| {
+        measure(name, bytes, iterations, || {
+            kernel(black_box(input), black_box(&parsed))
+        })
+    }));
+    Ok(stats)
+}
+
+/// Render the stats of a day as a JSON object
+pub fn to_json(day: u8, stats: &[Stats]) -> String {
+    format!(
+        "{{\"day\":{},\"results\":[{}]}}",
+        day,
+        stats
+            .iter()
+            .map(Stats::to_json)
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{bench, measure, to_json, Stats};
+    use crate::day5::Day5;
+
+    #[test]
+    fn measure_runs_at_least_once() {
+        let mut runs = 0;
+        let stats = measure("noop", 0, 0, || runs += 1);
+        assert_eq!(runs, 1);
+        assert_eq!(stats.iterations, 1);
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+    }
+
+    #[test]
+    fn median_of_an_even_number_of_runs() {
+        let mut runs = 0;
+        let stats = measure("sleep", 0, 2, || {
+            runs += 1;
+            std::thread::sleep(Duration::from_millis(if runs == 1 { 1 } else { 20 }));
+        });
+        assert!(stats.min < stats.median && stats.median < stats.max);
+    }
+
+    #[test]
+    fn throughput_uses_the_median() {
+        let stats = Stats {
+            name: "x".to_string(),
+            iterations: 3,
+            bytes: 1000,
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(500),
+            max: Duration::from_secs(10),
+        };
+        assert_eq!(stats.throughput(), 2000.0);
+        assert_eq!(
+            to_json(1, &[stats]),
+            "{\"day\":1,\"results\":[{\"name\":\"x\",\"iterations\":3,\"bytes\":1000,\"min_ns\":1000000,\"median_ns\":500000000,\"max_ns\":10000000000,\"bytes_per_sec\":2000}]}"
+        );
+    }
+
+    #[test]
+    fn bench_covers_parse_and_parts() {
+        let input =
+            std::fs::read_to_string(crate::input::data_dir().join("day5_example.dat")).unwrap();
+        let names: Vec<String> = bench::<Day5>(&input, 2)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        assert_eq!(names, vec!["parse", "part1", "part2"]);
+    }
+}
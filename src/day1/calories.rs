@@ -0,0 +1,37 @@
+use std::{
+    fmt::{Debug, Display},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+/// Unsigned integer type the calories are counted with.
+/// Sums are checked: an overflow is reported instead of wrapping around.
+pub trait Calories:
+    Copy + Ord + Default + Debug + Display + FromStr<Err = ParseIntError> + Into<u128> + TryFrom<u128>
+{
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Sum of the calories, `None` on overflow
+    fn checked_sum(calories: impl IntoIterator<Item = Self>) -> Option<Self> {
+        calories
+            .into_iter()
+            .try_fold(Self::default(), Self::checked_add)
+    }
+
+    /// Name of the type, for error messages
+    fn name() -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+macro_rules! impl_calories {
+    ($($t:ty),*) => {
+        $(impl Calories for $t {
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_calories!(u32, u64, u128);
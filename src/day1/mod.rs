@@ -0,0 +1,429 @@
+mod calories;
+mod reader;
+mod report;
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    io::BufRead,
+};
+
+pub use calories::Calories;
+pub use reader::ElfReader;
+pub use report::{read_report, report, Report};
+
+use crate::{Error, Result, Solution};
+
+/// The `N` best values proposed so far.
+/// On ties, the value proposed first ranks first and keeps its place.
+#[derive(Debug)]
+pub struct Podium<T, const N: usize> {
+    /// Min-heap: the worst member is on top, ready to be replaced
+    heap: BinaryHeap<Reverse<Ranked<T>>>,
+    proposed: usize,
+}
+
+/// A podium member and the order in which it was proposed
+#[derive(Debug)]
+struct Ranked<T> {
+    value: T,
+    order: usize,
+}
+
+impl<T: Ord> PartialEq for Ranked<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for Ranked<T> {}
+
+impl<T: Ord> PartialOrd for Ranked<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Ranked<T> {
+    /// Greater is better: the higher value, then the first proposed
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value
+            .cmp(&other.value)
+            .then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+impl<T: Ord, const N: usize> Default for Podium<T, N> {
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::with_capacity(N),
+            proposed: 0,
+        }
+    }
+}
+
+impl<T: Ord, const N: usize> Podium<T, N> {
+    pub fn propose(&mut self, candidate: T) {
+        let candidate = Ranked {
+            value: candidate,
+            order: self.proposed,
+        };
+        self.proposed += 1;
+        if self.heap.len() < N {
+            self.heap.push(Reverse(candidate));
+        } else if let Some(mut worst) = self.heap.peek_mut() {
+            if candidate.value > worst.0.value {
+                *worst = Reverse(candidate);
+            }
+        }
+    }
+
+    /// Members of the podium, best first
+    pub fn ranked(&self) -> Vec<&T> {
+        let mut ranked: Vec<&Ranked<T>> = self.heap.iter().map(|r| &r.0).collect();
+        ranked.sort_by(|a, b| b.cmp(a));
+        ranked.into_iter().map(|r| &r.value).collect()
+    }
+
+    /// Number of members, `N` once `N` values were proposed
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn best(&self) -> Option<&T> {
+        self.heap.iter().map(|r| &r.0).max().map(|r| &r.value)
+    }
+}
+
+impl<C: Calories, const N: usize> Podium<Elf<C>, N> {
+    /// Calories carried by the whole podium, `None` on overflow
+    fn sum_calories(&self) -> Option<C> {
+        C::checked_sum(self.heap.iter().map(|r| r.0.value.calories()))
+    }
+}
+
+/// An elf, with its position in the inventory, the calories it carries and its items.
+/// Elves are compared on their calories only.
+#[derive(Default, Debug, Clone)]
+pub struct Elf<C = u32>(usize, C, Vec<C>);
+
+impl<C: Calories> Elf<C> {
+    /// `None` when the calories of the items overflow `C`
+    pub fn new(index: usize, items: Vec<C>) -> Option<Self> {
+        Some(Self(index, C::checked_sum(items.iter().copied())?, items))
+    }
+
+    /// Position of the elf in the inventory, starting at 0
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    pub fn calories(&self) -> C {
+        self.1
+    }
+
+    /// Calories of each item, in the inventory order
+    pub fn items(&self) -> &[C] {
+        &self.2
+    }
+}
+
+impl<C: Calories> PartialEq for Elf<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<C: Calories> Eq for Elf<C> {}
+
+impl<C: Calories> PartialOrd for Elf<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Calories> Ord for Elf<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
+/// The `N` elves carrying the most calories
+pub fn parse_podium<C: Calories, const N: usize>(input: &str) -> Result<Podium<Elf<C>, N>> {
+    read_podium(input.as_bytes())
+}
+
+/// The `N` elves carrying the most calories, the inventory being streamed from `reader`
+pub fn read_podium<C: Calories, R: BufRead, const N: usize>(
+    reader: R,
+) -> Result<Podium<Elf<C>, N>> {
+    ElfReader::new(reader).try_fold(Podium::default(), |mut podium, elf| {
+        podium.propose(elf?);
+        Ok(podium)
+    })
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Podium<Elf<u64>, 3>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_podium(input)
+    }
+
+    /// The inventory is streamed, only the podium is kept in memory
+    fn read(reader: &mut dyn BufRead) -> Result<Self::Input> {
+        read_podium(reader)
+    }
+
+    /// Calories carried by the elf that picked the most
+    fn part1(podium: &Self::Input) -> Result<Self::Answer1> {
+        podium
+            .best()
+            .map(Elf::calories)
+            .ok_or_else(|| Error::unsolvable("no elf in the inventory"))
+    }
+
+    /// Calories carried by the top 3 elves
+    fn part2(podium: &Self::Input) -> Result<Self::Answer2> {
+        if podium.is_empty() {
+            return Err(Error::unsolvable("no elf in the inventory"));
+        }
+        if podium.len() < 3 {
+            return Err(Error::unsolvable(format!(
+                "only {} elves in the inventory, the top 3 needs 3",
+                podium.len()
+            )));
+        }
+        podium
+            .sum_calories()
+            .ok_or_else(|| Error::unsolvable("the calories of the top 3 elves overflow u64"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufReader, Read};
+
+    use super::{parse_podium, read_podium, Day1, Elf, ElfReader, Podium};
+    use crate::{Error, Solution};
+
+    const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(Day1::part1(&Day1::parse(INPUT).unwrap()), Ok(24000));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day1::part2(&Day1::parse(INPUT).unwrap()), Ok(45000));
+    }
+
+    #[test]
+    fn small_inventories_have_no_top_3() {
+        let empty = Day1::parse("").unwrap();
+        assert_eq!(
+            Day1::part1(&empty),
+            Err(Error::unsolvable("no elf in the inventory"))
+        );
+        assert_eq!(
+            Day1::part2(&empty),
+            Err(Error::unsolvable("no elf in the inventory"))
+        );
+        let two = Day1::parse("1000\n\n2000\n").unwrap();
+        assert_eq!(Day1::part1(&two), Ok(2000));
+        assert_eq!(
+            Day1::part2(&two),
+            Err(Error::unsolvable(
+                "only 2 elves in the inventory, the top 3 needs 3"
+            ))
+        );
+    }
+
+    #[test]
+    fn invalid_calories_are_located() {
+        assert_eq!(
+            Day1::parse("1000\n\n20OO\n").err(),
+            Some(Error::parse(
+                3,
+                1,
+                "Impossible to parse integer \"20OO\": invalid digit found in string"
+            ))
+        );
+    }
+
+    #[test]
+    fn podiums_of_any_size() {
+        let top1 = parse_podium::<u32, 1>(INPUT).unwrap();
+        assert_eq!(
+            top1.ranked().iter().map(|e| e.index()).collect::<Vec<_>>(),
+            vec![3]
+        );
+
+        let top10 = parse_podium::<u32, 10>(INPUT).unwrap();
+        let ranked: Vec<(usize, u32)> = top10
+            .ranked()
+            .iter()
+            .map(|e| (e.index(), e.calories()))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![(3, 24000), (2, 11000), (4, 10000), (0, 6000), (1, 4000)]
+        );
+
+        let top0 = parse_podium::<u32, 0>(INPUT).unwrap();
+        assert!(top0.ranked().is_empty());
+    }
+
+    #[test]
+    fn ties_keep_the_first_proposed() {
+        let mut podium = Podium::<Elf, 2>::default();
+        [5, 7, 5, 7, 7, 6]
+            .into_iter()
+            .enumerate()
+            .for_each(|(idx, cal)| podium.propose(Elf::new(idx, vec![cal]).unwrap()));
+        let who: Vec<usize> = podium.ranked().iter().map(|e| e.index()).collect();
+        assert_eq!(who, vec![1, 3]);
+        assert_eq!(podium.best().map(Elf::index), Some(1));
+    }
+
+    #[test]
+    fn an_empty_inventory_has_no_answer() {
+        assert!(matches!(
+            Day1::part1(&Podium::default()),
+            Err(Error::Unsolvable(_))
+        ));
+    }
+
+    #[test]
+    fn crlf_and_blank_lines_are_tolerated() {
+        const CRLF: &str = "\r\n\r\n1000\r\n2000\r\n\r\n\r\n\r\n4000\r\n\r\n\r\n";
+        let elves: Vec<(usize, u32)> = ElfReader::new(CRLF.as_bytes())
+            .map(|elf| elf.map(|e| (e.index(), e.calories())))
+            .collect::<crate::Result<_>>()
+            .unwrap();
+        assert_eq!(elves, vec![(0, 3000), (1, 4000)]);
+    }
+
+    #[test]
+    fn invalid_calories_are_located_when_streaming() {
+        let mut reader = ElfReader::<_, u32>::new("1\r\n\r\n2\r\n-3\r\n".as_bytes());
+        assert!(reader.next().unwrap().is_ok());
+        assert_eq!(
+            reader.next().unwrap().err(),
+            Some(Error::parse(
+                4,
+                1,
+                "Impossible to parse integer \"-3\": invalid digit found in string"
+            ))
+        );
+    }
+
+    /// An endless inventory generated on the fly: elf `n` carries `n % 1000` calories
+    struct Inventory {
+        elf: u32,
+        pending: Vec<u8>,
+    }
+
+    impl Read for Inventory {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.pending.is_empty() {
+                self.pending = format!("{}\n\n", self.elf % 1000).into_bytes();
+                self.elf += 1;
+            }
+            let len = buf.len().min(self.pending.len());
+            buf[..len].copy_from_slice(&self.pending[..len]);
+            self.pending.drain(..len);
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn large_inventories_are_streamed() {
+        let inventory = Inventory {
+            elf: 0,
+            pending: vec![],
+        };
+        let podium: Podium<Elf, 3> =
+            read_podium(BufReader::new(inventory.take(2_000_000))).unwrap();
+        let ranked: Vec<(usize, u32)> = podium
+            .ranked()
+            .iter()
+            .map(|e| (e.index(), e.calories()))
+            .collect();
+        assert_eq!(ranked, vec![(999, 999), (1999, 999), (2999, 999)]);
+    }
+
+    #[test]
+    fn overflowing_elves_are_reported() {
+        const INPUT: &str = "1\n\n4294967295\n1\n";
+        assert_eq!(
+            parse_podium::<u32, 3>(INPUT).err(),
+            Some(Error::parse(4, 1, "calories of elf 1 overflow u32"))
+        );
+        let podium = parse_podium::<u64, 3>(INPUT).unwrap();
+        assert_eq!(podium.best().map(Elf::calories), Some(4294967296));
+        assert!(Elf::new(0, vec![u32::MAX, 1]).is_none());
+    }
+
+    #[test]
+    fn items_too_large_for_the_calorie_type_are_located() {
+        assert_eq!(
+            parse_podium::<u32, 3>("1\n\n4294967296\n").err(),
+            Some(Error::parse(
+                3,
+                1,
+                "Impossible to parse integer \"4294967296\": number too large to fit in target type"
+            ))
+        );
+    }
+
+    #[test]
+    fn u128_calories_up_to_the_edge() {
+        let edge = format!("{}\n0\n", u128::MAX);
+        let podium = parse_podium::<u128, 1>(&edge).unwrap();
+        assert_eq!(podium.best().map(Elf::calories), Some(u128::MAX));
+
+        let over = format!("0\n\n{}\n\n{}\n1\n", u128::MAX, u128::MAX);
+        assert_eq!(
+            parse_podium::<u128, 1>(&over).err(),
+            Some(Error::parse(6, 1, "calories of elf 2 overflow u128"))
+        );
+    }
+
+    #[test]
+    fn podium_sums_are_checked() {
+        let input = format!("{0}\n\n{0}\n\n{0}\n", u64::MAX);
+        let podium = Day1::parse(&input).unwrap();
+        assert_eq!(Day1::part1(&podium), Ok(u64::MAX));
+        assert_eq!(
+            Day1::part2(&podium),
+            Err(Error::unsolvable(
+                "the calories of the top 3 elves overflow u64"
+            ))
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::{io::BufRead, marker::PhantomData};
+
+use super::{Calories, Elf};
+use crate::{Error, Result};
+
+/// Reads an inventory one elf at a time, keeping only the current line in memory.
+/// Elves are separated by blank lines; CRLF line endings and extra blank lines are accepted.
+/// Calories are counted with `C`, an elf carrying more than `C` can count is an error.
+pub struct ElfReader<R, C = u32> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    elves: usize,
+    calories: PhantomData<C>,
+}
+
+impl<R: BufRead, C: Calories> ElfReader<R, C> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            elves: 0,
+            calories: PhantomData,
+        }
+    }
+}
+
+impl<R: BufRead, C: Calories> Iterator for ElfReader<R, C> {
+    type Item = Result<Elf<C>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items: Vec<C> = vec![];
+        let mut calories = C::default();
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => break,
+                Ok(_) => self.line_number += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+            let value = self.line.trim_end_matches(['\n', '\r']);
+            if value.is_empty() {
+                if !items.is_empty() {
+                    break;
+                }
+                continue;
+            }
+            match value.parse::<C>() {
+                Ok(item) => {
+                    let Some(sum) = calories.checked_add(item) else {
+                        return Some(Err(Error::parse(
+                            self.line_number,
+                            1,
+                            format!("calories of elf {} overflow {}", self.elves, C::name()),
+                        )));
+                    };
+                    calories = sum;
+                    items.push(item);
+                }
+                Err(e) => {
+                    return Some(Err(Error::parse(
+                        self.line_number,
+                        1,
+                        format!("Impossible to parse integer {:?}: {}", value, e),
+                    )))
+                }
+            }
+        }
+        if items.is_empty() {
+            return None;
+        }
+        let elf = Elf(self.elves, calories, items);
+        self.elves += 1;
+        Some(Ok(elf))
+    }
+}
@@ -0,0 +1,300 @@
+use std::{fmt::Display, io::BufRead};
+
+use super::{Calories, Elf, ElfReader};
+use crate::{report::Format, Error, Result};
+
+/// Percentiles given in the report, the median included
+const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+/// Maximum number of bars of the histogram
+const BUCKETS: u128 = 10;
+
+/// Statistics about the calories carried by every elf of an inventory
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report<C = u32> {
+    pub elves: usize,
+    pub items: usize,
+    pub total: u128,
+    /// Calories carried by an elf, at each of `PERCENTILES`
+    pub percentiles: Vec<(u8, C)>,
+    pub histogram: Vec<Bucket<C>>,
+    pub min_items: usize,
+    pub max_items: usize,
+    /// The elf carrying the heaviest single item and the calories of that item
+    pub heaviest_item: (usize, C),
+}
+
+/// Number of elves carrying between `start` and `end` calories, both included
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket<C = u32> {
+    pub start: C,
+    pub end: C,
+    pub elves: usize,
+}
+
+impl<C: Calories> Report<C> {
+    pub fn from_elves(elves: impl IntoIterator<Item = Result<Elf<C>>>) -> Result<Self> {
+        let mut calories = vec![];
+        let mut items = vec![];
+        let mut heaviest_item = (0, C::default());
+        for elf in elves {
+            let elf = elf?;
+            if let Some(&heaviest) = elf.items().iter().max() {
+                if calories.is_empty() || heaviest > heaviest_item.1 {
+                    heaviest_item = (elf.index(), heaviest);
+                }
+            }
+            calories.push(elf.calories());
+            items.push(elf.items().len());
+        }
+        if calories.is_empty() {
+            return Err(Error::unsolvable("no elf in the inventory"));
+        }
+        calories.sort_unstable();
+        let total = u128::checked_sum(calories.iter().map(|&c| c.into()))
+            .ok_or_else(|| Error::unsolvable("the calories of all the elves overflow u128"))?;
+
+        Ok(Self {
+            elves: calories.len(),
+            items: items.iter().sum(),
+            total,
+            percentiles: PERCENTILES
+                .iter()
+                .map(|&p| (p, percentile(&calories, p)))
+                .collect(),
+            histogram: histogram(&calories),
+            min_items: items.iter().copied().min().unwrap_or(0),
+            max_items: items.iter().copied().max().unwrap_or(0),
+            heaviest_item,
+        })
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.total as f64 / self.elves as f64
+    }
+
+    pub fn mean_items(&self) -> f64 {
+        self.items as f64 / self.elves as f64
+    }
+
+    pub fn median(&self) -> C {
+        self.percentiles
+            .iter()
+            .find(|(p, _)| *p == 50)
+            .map_or(C::default(), |(_, calories)| *calories)
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"elves\":{},\"items\":{},\"total\":{},\"mean\":{:.2},\"median\":{},\"percentiles\":{{{}}},\"histogram\":[{}],\"items_per_elf\":{{\"min\":{},\"mean\":{:.2},\"max\":{}}},\"heaviest_item\":{{\"elf\":{},\"calories\":{}}}}}",
+            self.elves,
+            self.items,
+            self.total,
+            self.mean(),
+            self.median(),
+            self.percentiles
+                .iter()
+                .map(|(p, calories)| format!("\"p{}\":{}", p, calories))
+                .collect::<Vec<_>>()
+                .join(","),
+            self.histogram
+                .iter()
+                .map(|b| format!(
+                    "{{\"start\":{},\"end\":{},\"elves\":{}}}",
+                    b.start, b.end, b.elves
+                ))
+                .collect::<Vec<_>>()
+                .join(","),
+            self.min_items,
+            self.mean_items(),
+            self.max_items,
+            self.heaviest_item.0,
+            self.heaviest_item.1
+        )
+    }
+}
+
+impl<C: Calories> Display for Report<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "elves             {:>12}", self.elves)?;
+        writeln!(f, "items             {:>12}", self.items)?;
+        writeln!(f, "total calories    {:>12}", self.total)?;
+        writeln!(f, "mean              {:>12.2}", self.mean())?;
+        writeln!(f, "median            {:>12}", self.median())?;
+        writeln!(
+            f,
+            "items per elf     {:>12}",
+            format!(
+                "{} / {:.2} / {}",
+                self.min_items,
+                self.mean_items(),
+                self.max_items
+            )
+        )?;
+        writeln!(
+            f,
+            "heaviest item     {:>12} (elf {})",
+            self.heaviest_item.1, self.heaviest_item.0
+        )?;
+        writeln!(f, "\npercentile            calories")?;
+        for (p, calories) in &self.percentiles {
+            writeln!(f, "p{:<3}              {:>12}", p, calories)?;
+        }
+        writeln!(f, "\ncalories                     elves")?;
+        let widest = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        for bucket in &self.histogram {
+            let bar = (bucket.elves * 40).div_ceil(widest.max(1));
+            writeln!(
+                f,
+                "{:>10} - {:<10} {:>6} {}",
+                bucket.start,
+                bucket.end,
+                bucket.elves,
+                "#".repeat(bar)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Nearest rank percentile of sorted values, which must not be empty
+fn percentile<C: Calories>(sorted: &[C], p: u8) -> C {
+    let rank = (sorted.len() * p as usize).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Buckets of equal width covering sorted values, which must not be empty
+fn histogram<C: Calories>(sorted: &[C]) -> Vec<Bucket<C>> {
+    let (min, max): (u128, u128) = (sorted[0].into(), sorted[sorted.len() - 1].into());
+    let width = (max - min) / BUCKETS + 1;
+    // bounds lie between min and max, so they fit in C
+    let narrow = |c: u128| C::try_from(c.min(max)).ok().unwrap_or(sorted[0]);
+    let mut buckets: Vec<Bucket<C>> = (0..=(max - min) / width)
+        .map(|i| Bucket {
+            start: narrow(min + i * width),
+            end: narrow((min + i * width).saturating_add(width - 1)),
+            elves: 0,
+        })
+        .collect();
+    sorted.iter().for_each(|&c| {
+        let c: u128 = c.into();
+        buckets[((c - min) / width) as usize].elves += 1
+    });
+    buckets
+}
+
+/// Statistics of the inventory, rendered in `format`
+pub fn report(input: &str, format: Format) -> Result<String> {
+    read_report(input.as_bytes(), format)
+}
+
+/// The report of the inventory streamed from `reader`, one elf at a time
+pub fn read_report(reader: impl BufRead, format: Format) -> Result<String> {
+    let report = Report::<u64>::from_elves(ElfReader::new(reader))?;
+    Ok(match format {
+        Format::Text => report.to_string(),
+        Format::Json => report.to_json(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{report, Bucket, Report};
+    use crate::{day1::ElfReader, report::Format, Error};
+
+    const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn example_statistics() {
+        let report = Report::<u32>::from_elves(ElfReader::new(INPUT.as_bytes())).unwrap();
+        assert_eq!(report.elves, 5);
+        assert_eq!(report.items, 10);
+        assert_eq!(report.total, 55000);
+        assert_eq!(report.mean(), 11000.0);
+        assert_eq!(report.median(), 10000);
+        assert_eq!(
+            report.percentiles,
+            vec![
+                (10, 4000),
+                (25, 6000),
+                (50, 10000),
+                (75, 11000),
+                (90, 24000),
+                (99, 24000)
+            ]
+        );
+        assert_eq!((report.min_items, report.max_items), (1, 3));
+        assert_eq!(report.heaviest_item, (4, 10000));
+    }
+
+    #[test]
+    fn histogram_covers_every_elf() {
+        let report = Report::<u32>::from_elves(ElfReader::new(INPUT.as_bytes())).unwrap();
+        assert_eq!(report.histogram.len(), 10);
+        assert_eq!(
+            report.histogram[0],
+            Bucket {
+                start: 4000,
+                end: 6000,
+                elves: 2
+            }
+        );
+        assert_eq!(report.histogram[9].end, 24000);
+        assert_eq!(report.histogram.iter().map(|b| b.elves).sum::<usize>(), 5);
+
+        let single = Report::<u32>::from_elves(ElfReader::new("7\n".as_bytes())).unwrap();
+        assert_eq!(
+            single.histogram,
+            vec![Bucket {
+                start: 7,
+                end: 7,
+                elves: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_are_rendered() {
+        let json = report("1\n2\n\n3", Format::Json).unwrap();
+        assert_eq!(
+            json,
+            "{\"elves\":2,\"items\":3,\"total\":6,\"mean\":3.00,\"median\":3,\"percentiles\":{\"p10\":3,\"p25\":3,\"p50\":3,\"p75\":3,\"p90\":3,\"p99\":3},\"histogram\":[{\"start\":3,\"end\":3,\"elves\":2}],\"items_per_elf\":{\"min\":1,\"mean\":1.50,\"max\":2},\"heaviest_item\":{\"elf\":1,\"calories\":3}}"
+        );
+        let text = report(INPUT, Format::Text).unwrap();
+        assert!(text.contains("heaviest item            10000 (elf 4)"));
+        assert_eq!(
+            report("\n\n", Format::Text).err(),
+            Some(Error::unsolvable("no elf in the inventory"))
+        );
+    }
+
+    #[test]
+    fn totals_are_checked() {
+        let input = format!("{0}\n\n{0}\n", u128::MAX);
+        assert_eq!(
+            Report::<u128>::from_elves(ElfReader::new(input.as_bytes())).err(),
+            Some(Error::unsolvable(
+                "the calories of all the elves overflow u128"
+            ))
+        );
+
+        let input = format!("{0}\n\n{0}\n\n0\n", u64::MAX);
+        let report = Report::<u64>::from_elves(ElfReader::new(input.as_bytes())).unwrap();
+        assert_eq!(report.total, 2 * u64::MAX as u128);
+        assert_eq!(report.histogram.len(), 10);
+        assert_eq!(report.histogram[9].end, u64::MAX);
+        assert_eq!(report.histogram[9].elves, 2);
+    }
+}
@@ -0,0 +1,130 @@
+mod report;
+mod round;
+mod rules;
+mod strategy;
+
+pub use report::{report, report_with};
+pub use round::{parse_guide, total_score, Instruction, Round};
+pub use rules::{Column, Outcome, Rules, Shape, ShapeDef};
+pub use strategy::{
+    bounds, explain, simulate, simulate_random, Bounds, Breakdown, Opponent, Simulation,
+};
+
+use crate::{Result, Solution};
+
+/// For example, suppose you were given the following strategy guide:
+///
+/// A Y
+/// B X
+/// C Z
+///
+/// This strategy guide predicts and recommends the following:
+///
+/// - In the first round, your opponent will choose Rock (A), and you should choose Paper (Y). This ends in a win for you with a score of 8 (2 because you chose Paper + 6 because you won).
+/// - In the second round, your opponent will choose Paper (B), and you should choose Rock (X). This ends in a loss for you with a score of 1 (1 + 0).
+/// - The third round is a draw with both players choosing Scissors, giving you a score of 3 + 3 = 6.
+///
+/// In this example, if you were to follow the strategy guide, you would get a total score of 15 (8 + 1 + 6).
+///
+/// What would your total score be if everything goes exactly according to your strategy guide?
+///
+/// 1 ROCK       A   X
+/// 2 PAPER      B   Y
+/// 3 SCISSORS   C   Z
+///
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Round>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_guide(input, &Rules::classic())
+    }
+
+    /// The second column is the shape to play
+    fn part1(guide: &Self::Input) -> Result<Self::Answer1> {
+        total_score(guide, &Rules::classic(), Column::Shape)
+    }
+
+    /// The second column tells how the round must end (X lose, Y draw, Z win)
+    fn part2(guide: &Self::Input) -> Result<Self::Answer2> {
+        total_score(guide, &Rules::classic(), Column::Outcome)
+    }
+}
+
+/// Total scores of both parts of `input` played with `rules`, each part failing on its own
+pub fn play(input: &str, rules: &Rules) -> Result<Vec<Result<u32>>> {
+    let guide = parse_guide(input, rules)?;
+    Ok([Column::Shape, Column::Outcome]
+        .into_iter()
+        .map(|column| total_score(&guide, rules, column))
+        .collect())
+}
+
+#[test]
+fn test_example_part1() {
+    const INPUT: &str = "
+A Y
+B X
+C Z
+";
+    assert_eq!(Day2::part1(&Day2::parse(INPUT).unwrap()), Ok(15));
+}
+
+#[test]
+fn test_example_part2() {
+    const INPUT: &str = "
+A Y
+B X
+C Z
+";
+    assert_eq!(Day2::part2(&Day2::parse(INPUT).unwrap()), Ok(12));
+}
+
+#[test]
+fn test_rpsls_guide() {
+    let rules = Rules::rpsls();
+    let rounds = parse_guide("A W\nE V\nC Z\n", &rules).unwrap();
+    // spock beats rock, rock beats scissors, scissors beats paper
+    assert_eq!(
+        total_score(&rounds, &rules, Column::Shape),
+        Ok((5 + 6) + (1 + 6) + (3 + 6))
+    );
+    // draw with rock, lose with lizard against scissors, win with lizard against paper
+    let rounds = parse_guide("A Y\nE X\nC Z\n", &rules).unwrap();
+    assert_eq!(
+        total_score(&rounds, &rules, Column::Outcome),
+        Ok((1 + 3) + 4 + (4 + 6))
+    );
+}
+
+#[test]
+fn test_unknown_round_is_located() {
+    const INPUT: &str = "A Y
+B X
+C W
+";
+    assert_eq!(
+        Day2::parse(INPUT).err(),
+        Some(crate::Error::parse(3, 3, "unknown instruction \"W\""))
+    );
+}
+
+#[test]
+fn test_play_with_a_rules_file() {
+    let path = crate::input::repository_data_dir().join("day2_rpsls.rules");
+    let rules = Rules::parse(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(
+        play("A W\nE V\nC Z\n", &rules),
+        Ok(vec![
+            Ok((5 + 6) + (1 + 6) + (3 + 6)),
+            Err(crate::Error::unsolvable(
+                "the round on line 1 has no Outcome instruction"
+            ))
+        ])
+    );
+}
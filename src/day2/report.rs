@@ -0,0 +1,138 @@
+use super::{
+    parse_guide,
+    strategy::{bounds, explain, simulate_random, Breakdown, Simulation},
+    total_score, Column, Rules,
+};
+use crate::{report::Format, Result};
+
+/// Seed of the random opponents of the report, so that reports can be compared
+const SEED: u64 = 2022;
+/// Number of random opponents simulated by the report
+const RUNS: usize = 100;
+
+/// Scores of the guide for one interpretation of its second column
+struct Part {
+    column: Column,
+    score: u32,
+    simulation: Simulation,
+    rounds: Vec<Breakdown>,
+}
+
+/// Scores of the strategy guide: actual, best and worst possible, against random opponents,
+/// and the breakdown of every round, rendered in `format`
+pub fn report(input: &str, format: Format) -> Result<String> {
+    report_with(input, &Rules::classic(), format)
+}
+
+/// The report of a guide for the cyclic game of `rules`
+pub fn report_with(input: &str, rules: &Rules, format: Format) -> Result<String> {
+    let rounds = parse_guide(input, rules)?;
+    let bounds = bounds(&rounds, rules);
+    let parts = [Column::Shape, Column::Outcome]
+        .into_iter()
+        .map(|column| {
+            Ok(Part {
+                column,
+                score: total_score(&rounds, rules, column)?,
+                simulation: simulate_random(&rounds, rules, column, SEED, RUNS)?,
+                rounds: explain(&rounds, rules, column)?,
+            })
+        })
+        .collect::<Result<Vec<Part>>>()?;
+
+    Ok(match format {
+        Format::Text => {
+            let mut text = format!(
+                "{} rounds, best possible score {}, worst possible score {}\n",
+                rounds.len(),
+                bounds.best,
+                bounds.worst
+            );
+            for (idx, part) in parts.iter().enumerate() {
+                text += &format!(
+                    "\npart {} (second column is {}): score {}\n  {}\n",
+                    idx + 1,
+                    describe(part.column),
+                    part.score,
+                    part.simulation
+                );
+            }
+            text += "\nround  part 1                                  part 2\n";
+            for (idx, (first, second)) in parts[0].rounds.iter().zip(&parts[1].rounds).enumerate() {
+                text += &format!(
+                    "{:>5}  {:<38}  {}\n",
+                    idx + 1,
+                    first.describe(rules),
+                    second.describe(rules)
+                );
+            }
+            text
+        }
+        Format::Json => format!(
+            "{{\"rounds\":{},\"best\":{},\"worst\":{},\"parts\":[{}]}}",
+            rounds.len(),
+            bounds.best,
+            bounds.worst,
+            parts
+                .iter()
+                .map(|part| part_to_json(part, rules))
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+    })
+}
+
+fn describe(column: Column) -> &'static str {
+    match column {
+        Column::Shape => "my shape",
+        Column::Outcome => "the outcome",
+    }
+}
+
+fn part_to_json(part: &Part, rules: &Rules) -> String {
+    format!(
+        "{{\"column\":\"{:?}\",\"score\":{},\"random\":{{\"runs\":{},\"seed\":{},\"min\":{},\"mean\":{:.2},\"max\":{}}},\"rounds\":[{}]}}",
+        part.column,
+        part.score,
+        part.simulation.runs,
+        part.simulation.seed,
+        part.simulation.min,
+        part.simulation.mean,
+        part.simulation.max,
+        part.rounds
+            .iter()
+            .map(|b| format!(
+                "{{\"opponent\":\"{}\",\"mine\":\"{}\",\"outcome\":\"{:?}\",\"shape_score\":{},\"outcome_score\":{}}}",
+                rules.shape(b.opponent).name,
+                rules.shape(b.mine).name,
+                b.outcome,
+                b.shape_score,
+                b.outcome_score
+            ))
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::report;
+    use crate::report::Format;
+
+    #[test]
+    fn example_report() {
+        let text = report("A Y\nB X\nC Z\n", Format::Text).unwrap();
+        assert!(text.starts_with("3 rounds, best possible score 24, worst possible score 6\n"));
+        assert!(text.contains("part 1 (second column is my shape): score 15\n"));
+        assert!(text.contains("part 2 (second column is the outcome): score 12\n"));
+        assert!(text.contains(
+            "    1  paper vs rock: Win, 2 + 6 = 8           rock vs rock: Draw, 1 + 3 = 4\n"
+        ));
+
+        let json = report("A Y\n", Format::Json).unwrap();
+        assert!(json.starts_with(
+            "{\"rounds\":1,\"best\":8,\"worst\":3,\"parts\":[{\"column\":\"Shape\",\"score\":8,"
+        ));
+        assert!(json.ends_with("\"rounds\":[{\"opponent\":\"rock\",\"mine\":\"rock\",\"outcome\":\"Draw\",\"shape_score\":1,\"outcome_score\":3}]}]}"));
+    }
+}
@@ -0,0 +1,183 @@
+use super::{Column, Outcome, Rules, Shape};
+use crate::{
+    error::Failure,
+    parse::{pair, parse_all, preceded, terminated, try_map, whitespace, word},
+    Error, Result,
+};
+
+/// The second column of a round: a shape or an outcome, depending on the part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    /// The shape to play, when the column is read as a shape
+    pub shape: Option<Shape>,
+    /// How the round must end, when the column is read as an outcome
+    pub outcome: Option<Outcome>,
+}
+
+/// One line of a strategy guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub instruction: Instruction,
+    /// Line of the round in the guide, 1 based
+    pub line: usize,
+}
+
+impl Round {
+    /// A round such as `A Y`; codes are case insensitive and may be surrounded by whitespaces.
+    /// The round is taken as the first line of its guide.
+    pub fn parse<'s>(line: &'s str, rules: &Rules) -> std::result::Result<Self, Failure<'s>> {
+        let opponent = try_map(word, |code| {
+            rules
+                .opponent_shape(code)
+                .ok_or_else(|| format!("unknown opponent shape {:?}", code))
+        });
+        let instruction = try_map(word, |code| {
+            let instruction = Instruction {
+                shape: rules.my_shape(code),
+                outcome: rules.outcome_code(code),
+            };
+            match instruction {
+                Instruction {
+                    shape: None,
+                    outcome: None,
+                } => Err(format!("unknown instruction {:?}", code)),
+                instruction => Ok(instruction),
+            }
+        });
+        parse_all(
+            terminated(
+                pair(
+                    preceded(whitespace, opponent),
+                    preceded(whitespace, instruction),
+                ),
+                whitespace,
+            ),
+            line,
+        )
+        .map(|(opponent, instruction)| Round {
+            opponent,
+            instruction,
+            line: 1,
+        })
+    }
+
+    /// The shape I play in this round
+    pub fn my_shape(&self, rules: &Rules, column: Column) -> Option<Shape> {
+        match column {
+            Column::Shape => self.instruction.shape,
+            Column::Outcome => self
+                .instruction
+                .outcome
+                .map(|outcome| rules.shape_for(self.opponent, outcome)),
+        }
+    }
+}
+
+/// Every round of a strategy guide, blank lines being skipped
+pub fn parse_guide(input: &str, rules: &Rules) -> Result<Vec<Round>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            Round::parse(line, rules)
+                .map(|round| Round {
+                    line: idx + 1,
+                    ..round
+                })
+                .map_err(|f| f.locate(input))
+        })
+        .collect()
+}
+
+/// My total score when following the guide
+pub fn total_score(rounds: &[Round], rules: &Rules, column: Column) -> Result<u32> {
+    rounds
+        .iter()
+        .map(|round| {
+            round
+                .my_shape(rules, column)
+                .map(|mine| rules.score(mine, round.opponent))
+                .ok_or_else(|| missing_instruction(round, column))
+        })
+        .sum()
+}
+
+pub(super) fn missing_instruction(round: &Round, column: Column) -> Error {
+    Error::unsolvable(format!(
+        "the round on line {} has no {:?} instruction",
+        round.line, column
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_guide, total_score, Instruction, Round};
+    use crate::{
+        day2::{Column, Outcome, Rules},
+        error::Failure,
+        Error,
+    };
+
+    #[test]
+    fn rounds_are_parsed_loosely() {
+        let rules = Rules::classic();
+        let expected = Round {
+            opponent: rules.opponent_shape("A").unwrap(),
+            instruction: Instruction {
+                shape: rules.my_shape("Y"),
+                outcome: Some(Outcome::Draw),
+            },
+            line: 1,
+        };
+        assert_eq!(Round::parse("A Y", &rules), Ok(expected));
+        assert_eq!(Round::parse("  a \t y  \r", &rules), Ok(expected));
+    }
+
+    #[test]
+    fn malformed_rounds_are_reported() {
+        let rules = Rules::classic();
+        assert_eq!(
+            Round::parse("A Y Z", &rules),
+            Err(Failure::new("Z", "unexpected trailing characters"))
+        );
+        assert_eq!(
+            Round::parse("A", &rules),
+            Err(Failure::new("", "expected a word"))
+        );
+        assert_eq!(
+            parse_guide("A Y\n\nB X\nD Z\n", &rules),
+            Err(Error::parse(4, 1, "unknown opponent shape \"D\""))
+        );
+        assert_eq!(
+            parse_guide("A Y\nB  W\n", &rules),
+            Err(Error::parse(2, 4, "unknown instruction \"W\""))
+        );
+    }
+
+    #[test]
+    fn instructions_missing_for_a_column_are_reported() {
+        let rules = Rules::rpsls();
+        let rounds = parse_guide("A V\nB X\n", &rules).unwrap();
+        assert_eq!(
+            total_score(&rounds, &rules, Column::Shape),
+            Ok((1 + 3) + (2 + 6))
+        );
+        assert_eq!(
+            total_score(&rounds, &rules, Column::Outcome),
+            Err(Error::unsolvable(
+                "the round on line 1 has no Outcome instruction"
+            ))
+        );
+        // blank lines are skipped but still counted
+        let rounds = parse_guide("\nB X\n\nA V\n", &rules).unwrap();
+        assert_eq!(rounds.iter().map(|r| r.line).collect::<Vec<_>>(), [2, 4]);
+        assert_eq!(
+            total_score(&rounds, &rules, Column::Outcome),
+            Err(Error::unsolvable(
+                "the round on line 4 has no Outcome instruction"
+            ))
+        );
+    }
+}
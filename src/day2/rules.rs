@@ -0,0 +1,327 @@
+//! Rules of cyclic games such as Rock Paper Scissors, which can be read from a file:
+//!
+//! ```text
+//! # shape <name> <opponent code> <my code> <score>, in cyclic order:
+//! # each shape beats the half of the shapes listed right before it
+//! shape rock     A X 1
+//! shape paper    B Y 2
+//! shape scissors C Z 3
+//! # codes telling how the round must end: lose, draw, win (default X Y Z)
+//! outcomes X Y Z
+//! ```
+
+use crate::{error::Failure, Result};
+
+/// One shape of the game and how strategy guides refer to it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapeDef {
+    pub name: String,
+    /// Code of the shape in the first column
+    pub opponent: String,
+    /// Code of the shape in the second column, when it is the shape to play
+    pub mine: String,
+    pub score: u32,
+}
+
+/// A shape, by its position in the rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shape(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// What the second column of a strategy guide tells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    /// The shape to play
+    Shape,
+    /// How the round must end
+    Outcome,
+}
+
+/// A cyclic game: with `n` shapes, each shape beats the `(n - 1) / 2` shapes before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rules {
+    shapes: Vec<ShapeDef>,
+    /// Codes of the outcomes: lose, draw and win
+    outcomes: [String; 3],
+}
+
+const OUTCOMES: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+fn shape(name: &str, opponent: &str, mine: &str, score: u32) -> ShapeDef {
+    ShapeDef {
+        name: name.to_string(),
+        opponent: opponent.to_string(),
+        mine: mine.to_string(),
+        score,
+    }
+}
+
+fn default_outcomes() -> [String; 3] {
+    ["X", "Y", "Z"].map(String::from)
+}
+
+impl Rules {
+    /// Rock Paper Scissors, as played by the elves
+    pub fn classic() -> Self {
+        Self {
+            shapes: vec![
+                shape("rock", "A", "X", 1),
+                shape("paper", "B", "Y", 2),
+                shape("scissors", "C", "Z", 3),
+            ],
+            outcomes: default_outcomes(),
+        }
+    }
+
+    /// Rock Paper Scissors Lizard Spock
+    pub fn rpsls() -> Self {
+        Self {
+            shapes: vec![
+                shape("rock", "A", "V", 1),
+                shape("spock", "B", "W", 5),
+                shape("paper", "C", "X", 2),
+                shape("lizard", "D", "Y", 4),
+                shape("scissors", "E", "Z", 3),
+            ],
+            outcomes: default_outcomes(),
+        }
+    }
+
+    /// Read rules written in the format described at the top of this module
+    pub fn parse(s: &str) -> Result<Self> {
+        let locate = |at, message: String| Failure::new(at, message).locate(s);
+        let mut shapes: Vec<ShapeDef> = vec![];
+        let mut outcomes = None;
+        for line in s.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["shape", name, opponent, mine, score] => {
+                    let score = score
+                        .parse::<u32>()
+                        .map_err(|e| locate(score, format!("invalid score: {}", e)))?;
+                    if shapes.iter().any(|s| s.opponent.eq_ignore_ascii_case(opponent)) {
+                        return Err(locate(opponent, format!("code {} used twice", opponent)));
+                    }
+                    if shapes.iter().any(|s| s.mine.eq_ignore_ascii_case(mine)) {
+                        return Err(locate(mine, format!("code {} used twice", mine)));
+                    }
+                    shapes.push(shape(name, opponent, mine, score));
+                }
+                ["outcomes", lose, draw, win] => {
+                    let same = |a: &str, b: &str| a.eq_ignore_ascii_case(b);
+                    if same(lose, draw) || same(draw, win) || same(lose, win) {
+                        return Err(locate(line, "outcome codes must differ".to_string()));
+                    }
+                    outcomes = Some([lose, draw, win].map(|c| c.to_string()));
+                }
+                _ => {
+                    return Err(locate(
+                        line,
+                        "expected `shape <name> <opponent> <mine> <score>` or `outcomes <lose> <draw> <win>`"
+                            .to_string(),
+                    ))
+                }
+            }
+        }
+        if shapes.len() < 3 || shapes.len().is_multiple_of(2) {
+            return Err(locate(
+                &s[s.len()..],
+                format!(
+                    "a cyclic game needs an odd number of shapes, at least 3, got {}",
+                    shapes.len()
+                ),
+            ));
+        }
+        Ok(Self {
+            shapes,
+            outcomes: outcomes.unwrap_or_else(default_outcomes),
+        })
+    }
+
+    pub fn shape(&self, shape: Shape) -> &ShapeDef {
+        &self.shapes[shape.0]
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.shapes.len()).map(Shape)
+    }
+
+    /// Shape designated by `code` in the first column, ignoring the case
+    pub fn opponent_shape(&self, code: &str) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|s| s.opponent.eq_ignore_ascii_case(code))
+            .map(Shape)
+    }
+
+    /// Shape designated by `code` in the second column, ignoring the case
+    pub fn my_shape(&self, code: &str) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|s| s.mine.eq_ignore_ascii_case(code))
+            .map(Shape)
+    }
+
+    /// Outcome designated by `code` in the second column, ignoring the case
+    pub fn outcome_code(&self, code: &str) -> Option<Outcome> {
+        self.outcomes
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(code))
+            .map(|i| OUTCOMES[i])
+    }
+
+    /// How the round ends for me
+    pub fn outcome(&self, mine: Shape, opponent: Shape) -> Outcome {
+        let n = self.shapes.len();
+        match (mine.0 + n - opponent.0) % n {
+            0 => Outcome::Draw,
+            d if d <= (n - 1) / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// The shape to play against `opponent` so that the round ends with `outcome`.
+    /// When several shapes would do, the one closest to `opponent` in the cycle is chosen.
+    pub fn shape_for(&self, opponent: Shape, outcome: Outcome) -> Shape {
+        let n = self.shapes.len();
+        match outcome {
+            Outcome::Draw => opponent,
+            Outcome::Win => Shape((opponent.0 + 1) % n),
+            Outcome::Lose => Shape((opponent.0 + n - 1) % n),
+        }
+    }
+
+    /// My score for a round: the score of my shape plus the score of the outcome
+    pub fn score(&self, mine: Shape, opponent: Shape) -> u32 {
+        self.shape(mine).score + self.outcome(mine, opponent).score()
+    }
+
+    /// Score of a round given by its codes, `None` when a code is unknown
+    pub fn score_codes(&self, opponent: &str, second: &str, column: Column) -> Option<u32> {
+        let opponent = self.opponent_shape(opponent)?;
+        let mine = match column {
+            Column::Shape => self.my_shape(second)?,
+            Column::Outcome => self.shape_for(opponent, self.outcome_code(second)?),
+        };
+        Some(self.score(mine, opponent))
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Column, Outcome, Rules};
+    use crate::{input::data_dir, Error};
+
+    #[test]
+    fn classic_scores() {
+        let rules = Rules::classic();
+        let scores = |column| {
+            ["A", "B", "C"]
+                .iter()
+                .flat_map(|o| ["X", "Y", "Z"].map(|m| rules.score_codes(o, m, column).unwrap()))
+                .collect::<Vec<u32>>()
+        };
+        assert_eq!(scores(Column::Shape), vec![4, 8, 3, 1, 5, 9, 7, 2, 6]);
+        assert_eq!(scores(Column::Outcome), vec![3, 4, 8, 1, 5, 9, 2, 6, 7]);
+        assert_eq!(rules.score_codes("A", "W", Column::Shape), None);
+        assert_eq!(rules.score_codes("D", "X", Column::Outcome), None);
+    }
+
+    #[test]
+    fn every_rpsls_shape_beats_two_others() {
+        let rules = Rules::rpsls();
+        for mine in rules.shapes() {
+            let mut beaten: Vec<&str> = rules
+                .shapes()
+                .filter(|&other| rules.outcome(mine, other) == Outcome::Win)
+                .map(|other| rules.shape(other).name.as_str())
+                .collect();
+            beaten.sort();
+            let expected = match rules.shape(mine).name.as_str() {
+                "rock" => ["lizard", "scissors"],
+                "paper" => ["rock", "spock"],
+                "scissors" => ["lizard", "paper"],
+                "lizard" => ["paper", "spock"],
+                "spock" => ["rock", "scissors"],
+                other => panic!("unexpected shape {}", other),
+            };
+            assert_eq!(beaten, expected, "{}", rules.shape(mine).name);
+        }
+    }
+
+    #[test]
+    fn shape_for_reaches_the_outcome() {
+        for rules in [Rules::classic(), Rules::rpsls()] {
+            for opponent in rules.shapes() {
+                for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+                    let mine = rules.shape_for(opponent, outcome);
+                    assert_eq!(rules.outcome(mine, opponent), outcome);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rules_are_read_from_files() {
+        let text = std::fs::read_to_string(data_dir().join("day2_rpsls.rules")).unwrap();
+        assert_eq!(Rules::parse(&text), Ok(Rules::rpsls()));
+        assert_eq!(
+            Rules::parse("shape rock A X 1\nshape paper B Y 2\nshape scissors C Z 3\n"),
+            Ok(Rules::classic())
+        );
+        let custom =
+            Rules::parse("shape a 1 a 1\nshape b 2 b 2\nshape c 3 c 3\noutcomes l d w").unwrap();
+        assert_eq!(custom.score_codes("1", "w", Column::Outcome), Some(8));
+    }
+
+    #[test]
+    fn invalid_rules_are_located() {
+        assert_eq!(
+            Rules::parse("shape rock A X 1\nshape paper A Y 2\n"),
+            Err(Error::parse(2, 13, "code A used twice"))
+        );
+        assert_eq!(
+            Rules::parse("shape rock A X one\n"),
+            Err(Error::parse(
+                1,
+                16,
+                "invalid score: invalid digit found in string"
+            ))
+        );
+        assert_eq!(
+            Rules::parse("shape rock A X 1\nshape paper B Y 2\n"),
+            Err(Error::parse(
+                3,
+                1,
+                "a cyclic game needs an odd number of shapes, at least 3, got 2"
+            ))
+        );
+        assert!(Rules::parse("rock A X 1\n").is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt::Display;
+
+use super::{round::missing_instruction, Column, Outcome, Round, Rules, Shape};
+use crate::{rng::Rng, Result};
+
+/// How one round is scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakdown {
+    pub opponent: Shape,
+    pub mine: Shape,
+    pub outcome: Outcome,
+    /// Score of the shape I play
+    pub shape_score: u32,
+    /// Score of the outcome of the round
+    pub outcome_score: u32,
+}
+
+impl Breakdown {
+    pub fn new(rules: &Rules, mine: Shape, opponent: Shape) -> Self {
+        let outcome = rules.outcome(mine, opponent);
+        Self {
+            opponent,
+            mine,
+            outcome,
+            shape_score: rules.shape(mine).score,
+            outcome_score: outcome.score(),
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        self.shape_score + self.outcome_score
+    }
+
+    /// Human description such as `paper vs rock: win, 2 + 6 = 8`
+    pub fn describe(&self, rules: &Rules) -> String {
+        format!(
+            "{} vs {}: {:?}, {} + {} = {}",
+            rules.shape(self.mine).name,
+            rules.shape(self.opponent).name,
+            self.outcome,
+            self.shape_score,
+            self.outcome_score,
+            self.score()
+        )
+    }
+}
+
+/// Breakdown of every round when following the guide
+pub fn explain(rounds: &[Round], rules: &Rules, column: Column) -> Result<Vec<Breakdown>> {
+    rounds
+        .iter()
+        .map(|round| {
+            round
+                .my_shape(rules, column)
+                .map(|mine| Breakdown::new(rules, mine, round.opponent))
+                .ok_or_else(|| missing_instruction(round, column))
+        })
+        .collect()
+}
+
+/// Lowest and highest total scores reachable against the opponent moves of the guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub worst: u32,
+    pub best: u32,
+}
+
+pub fn bounds(rounds: &[Round], rules: &Rules) -> Bounds {
+    rounds
+        .iter()
+        .fold(Bounds { worst: 0, best: 0 }, |acc, round| {
+            let scores = rules.shapes().map(|mine| rules.score(mine, round.opponent));
+            let (worst, best) = scores.fold((u32::MAX, 0), |(lo, hi), s| (lo.min(s), hi.max(s)));
+            Bounds {
+                worst: acc.worst + worst,
+                best: acc.best + best,
+            }
+        })
+}
+
+/// Moves of the opponent during a simulation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opponent {
+    /// The opponent plays as the guide predicts
+    Replay,
+    /// The opponent plays uniformly at random, ignoring the guide
+    Random { seed: u64 },
+}
+
+/// My total score when playing the shapes the guide recommends against `opponent`.
+/// My shapes are chosen from the predicted moves, whatever the opponent really plays.
+pub fn simulate(
+    rounds: &[Round],
+    rules: &Rules,
+    column: Column,
+    opponent: Opponent,
+) -> Result<u32> {
+    let mut rng = match opponent {
+        Opponent::Replay => None,
+        Opponent::Random { seed } => Some(Rng::new(seed)),
+    };
+    let shapes: Vec<Shape> = rules.shapes().collect();
+    rounds
+        .iter()
+        .map(|round| {
+            let mine = round
+                .my_shape(rules, column)
+                .ok_or_else(|| missing_instruction(round, column))?;
+            let played = match rng.as_mut() {
+                Some(rng) => shapes[rng.below(shapes.len() as u64) as usize],
+                None => round.opponent,
+            };
+            Ok(rules.score(mine, played))
+        })
+        .sum()
+}
+
+/// Scores of several simulations against random opponents
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simulation {
+    pub runs: usize,
+    pub seed: u64,
+    pub min: u32,
+    pub mean: f64,
+    pub max: u32,
+}
+
+/// Run `runs` simulations against random opponents, the i-th one seeded with `seed + i`
+pub fn simulate_random(
+    rounds: &[Round],
+    rules: &Rules,
+    column: Column,
+    seed: u64,
+    runs: usize,
+) -> Result<Simulation> {
+    let scores = (0..runs.max(1) as u64)
+        .map(|i| {
+            simulate(
+                rounds,
+                rules,
+                column,
+                Opponent::Random {
+                    seed: seed.wrapping_add(i),
+                },
+            )
+        })
+        .collect::<Result<Vec<u32>>>()?;
+    Ok(Simulation {
+        runs: scores.len(),
+        seed,
+        min: scores.iter().copied().min().unwrap_or(0),
+        mean: scores.iter().map(|&s| s as f64).sum::<f64>() / scores.len() as f64,
+        max: scores.iter().copied().max().unwrap_or(0),
+    })
+}
+
+impl Display for Simulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {} / mean {:.2} / max {} over {} random opponents (seed {})",
+            self.min, self.mean, self.max, self.runs, self.seed
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bounds, explain, simulate, simulate_random, Bounds, Opponent};
+    use crate::day2::{parse_guide, total_score, Column, Outcome, Rules};
+
+    const INPUT: &str = "A Y\nB X\nC Z\n";
+
+    #[test]
+    fn rounds_are_explained() {
+        let rules = Rules::classic();
+        let rounds = parse_guide(INPUT, &rules).unwrap();
+        let breakdown = explain(&rounds, &rules, Column::Shape).unwrap();
+        let described: Vec<String> = breakdown.iter().map(|b| b.describe(&rules)).collect();
+        assert_eq!(
+            described,
+            vec![
+                "paper vs rock: Win, 2 + 6 = 8",
+                "rock vs paper: Lose, 1 + 0 = 1",
+                "scissors vs scissors: Draw, 3 + 3 = 6"
+            ]
+        );
+        let outcomes: Vec<Outcome> = explain(&rounds, &rules, Column::Outcome)
+            .unwrap()
+            .iter()
+            .map(|b| b.outcome)
+            .collect();
+        assert_eq!(outcomes, vec![Outcome::Draw, Outcome::Lose, Outcome::Win]);
+    }
+
+    #[test]
+    fn best_and_worst_scores() {
+        let rules = Rules::classic();
+        let rounds = parse_guide(INPUT, &rules).unwrap();
+        // best: paper vs rock 8, scissors vs paper 9, rock vs scissors 7
+        // worst: scissors vs rock 3, rock vs paper 1, paper vs scissors 2
+        assert_eq!(bounds(&rounds, &rules), Bounds { worst: 6, best: 24 });
+        for column in [Column::Shape, Column::Outcome] {
+            let score = total_score(&rounds, &rules, column).unwrap();
+            assert!((6..=24).contains(&score));
+        }
+    }
+
+    #[test]
+    fn replays_match_the_guide() {
+        let rules = Rules::rpsls();
+        let rounds = parse_guide("A W\nE V\nC Z\nB X\n", &rules).unwrap();
+        assert_eq!(
+            simulate(&rounds, &rules, Column::Shape, Opponent::Replay),
+            total_score(&rounds, &rules, Column::Shape)
+        );
+    }
+
+    #[test]
+    fn random_opponents_are_reproducible() {
+        let rules = Rules::classic();
+        let rounds = parse_guide(&INPUT.repeat(100), &rules).unwrap();
+        let random = Opponent::Random { seed: 7 };
+        let score = simulate(&rounds, &rules, Column::Shape, random).unwrap();
+        assert_eq!(simulate(&rounds, &rules, Column::Shape, random), Ok(score));
+        // whatever the opponent plays, each round scores between my shape's loss and win
+        let (worst, best) = rounds.iter().fold((0, 0), |(lo, hi), round| {
+            let mine = round.my_shape(&rules, Column::Shape).unwrap();
+            let scores: Vec<u32> = rules.shapes().map(|o| rules.score(mine, o)).collect();
+            (
+                lo + scores.iter().min().unwrap(),
+                hi + scores.iter().max().unwrap(),
+            )
+        });
+        assert!((worst..=best).contains(&score));
+
+        let simulation = simulate_random(&rounds, &rules, Column::Shape, 7, 20).unwrap();
+        assert_eq!(simulation.runs, 20);
+        assert!(
+            simulation.min as f64 <= simulation.mean && simulation.mean <= simulation.max as f64
+        );
+        // shapes 2, 1 and 3 against a random opponent: 2 + 1 + 3 + 3 * 3 on average per 3 rounds
+        assert!((simulation.mean - 1500.0).abs() < 100.0, "{}", simulation);
+    }
+}
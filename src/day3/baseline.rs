@@ -0,0 +1,59 @@
+//! The original computation of the items of a rucksack, kept word for word as the reference
+//! kernel of `aoc bench 3`. Characters out of `a-zA-Z` are truncated to a byte and count as
+//! priority 0.
+
+pub(super) fn compute_bitflags_for_string(s: &str) -> u64 {
+    s.chars().fold(0_u64, |bits, c| {
+        set_letter_bitflags(bits, letter_priority(c))
+    })
+}
+
+/// Returns the letter priority (1-52) as per the day3 notice
+fn letter_priority(letter: char) -> u8 {
+    let letter = letter as u8;
+    const MAJ_OFFSET: u8 = b'A' - 27;
+    const MIN_OFFSET: u8 = b'a' - 1;
+    match letter {
+        maj @ 65..=90 => {
+            // A -> 27
+            // Z -> 52
+            maj - MAJ_OFFSET
+        }
+        min @ 97..=122 => {
+            // a -> 1
+            // z -> 26
+            min - MIN_OFFSET
+        }
+        _ => 0, // 2^0
+    }
+}
+
+fn set_letter_bitflags(mut acc: u64, letter: u8) -> u64 {
+    acc |= 1 << letter;
+    acc
+}
+
+#[cfg(test)]
+mod test {
+    use super::compute_bitflags_for_string;
+    use crate::{input::repository_data_dir, letterset::Alphabet, Solution};
+
+    #[test]
+    fn baseline_matches_the_letter_sets() {
+        let input = std::fs::read_to_string(repository_data_dir().join("day3.dat")).unwrap();
+        let letters = Alphabet::letters();
+        for line in input.lines() {
+            let set = letters.set(line).unwrap();
+            let bits = set.iter().fold(0_u64, |bits, p| bits | 1 << (p + 1));
+            assert_eq!(compute_bitflags_for_string(line), bits, "{}", line);
+        }
+        // unknown characters are kept as priority 0
+        assert_eq!(compute_bitflags_for_string("a1"), 0b11);
+    }
+
+    #[test]
+    fn baseline_is_benchmarked() {
+        let kernels = super::super::Day3::kernels();
+        assert_eq!(kernels[0].0, "compute_bitflags_for_string");
+    }
+}
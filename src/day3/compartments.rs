@@ -0,0 +1,104 @@
+use crate::{
+    error::Failure,
+    letterset::{Alphabet, LetterSet},
+};
+
+/// Split a rucksack in compartments holding the same number of items
+pub trait Compartments {
+    /// None when the number of characters isn't a multiple of `count`, or `count` is 0
+    fn compartments(&self, count: usize) -> Option<Vec<&Self>>;
+}
+
+impl Compartments for str {
+    fn compartments(&self, count: usize) -> Option<Vec<&str>> {
+        let items = if self.is_ascii() {
+            self.len()
+        } else {
+            self.chars().count()
+        };
+        if count == 0 || !items.is_multiple_of(count) {
+            return None;
+        }
+        if self.is_ascii() {
+            // one byte per item, no need to decode the characters
+            let size = self.len() / count;
+            return Some(
+                (0..count)
+                    .map(|i| &self[i * size..(i + 1) * size])
+                    .collect(),
+            );
+        }
+        let mut bounds: Vec<usize> = self
+            .char_indices()
+            .map(|(i, _)| i)
+            .step_by((items / count).max(1))
+            .collect();
+        bounds.resize(count, self.len());
+        bounds.push(self.len());
+        Some(bounds.windows(2).map(|w| &self[w[0]..w[1]]).collect())
+    }
+}
+
+/// Items of the rucksack found in more than one of its `count` compartments
+pub fn duplicates<'s>(
+    bag: &'s str,
+    alphabet: &Alphabet,
+    count: usize,
+) -> Result<LetterSet, Failure<'s>> {
+    let compartments = bag.compartments(count).ok_or_else(|| {
+        Failure::new(
+            bag,
+            format!(
+                "{} items can't be split in {} compartments of the same size",
+                bag.chars().count(),
+                count
+            ),
+        )
+    })?;
+    let mut seen = LetterSet::EMPTY;
+    let mut duplicates = LetterSet::EMPTY;
+    for compartment in compartments {
+        let items = alphabet.set(compartment)?;
+        duplicates |= seen & items;
+        seen |= items;
+    }
+    Ok(duplicates)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{duplicates, Compartments};
+    use crate::letterset::Alphabet;
+
+    #[test]
+    fn rucksacks_are_split_equally() {
+        assert_eq!("abcdef".compartments(2), Some(vec!["abc", "def"]));
+        assert_eq!("abcdef".compartments(3), Some(vec!["ab", "cd", "ef"]));
+        assert_eq!("abcdef".compartments(1), Some(vec!["abcdef"]));
+        assert_eq!("ᚠᚱᚢᚱ".compartments(2), Some(vec!["ᚠᚱ", "ᚢᚱ"]));
+        assert_eq!("".compartments(2), Some(vec!["", ""]));
+        assert_eq!("abcdef".compartments(4), None);
+        assert_eq!("abc".compartments(0), None);
+    }
+
+    #[test]
+    fn duplicates_of_every_compartment() {
+        let letters = Alphabet::letters();
+        let set = |s| letters.set(s).unwrap();
+        assert_eq!(duplicates("abcabd", &letters, 2), Ok(set("ab")));
+        // a in the first and third, c in the second and third
+        assert_eq!(duplicates("abcdac", &letters, 3), Ok(set("ac")));
+        assert_eq!(duplicates("aabb", &letters, 2), Ok(set("")));
+        assert_eq!(duplicates("abab", &letters, 4), Ok(set("ab")));
+
+        let failure = duplicates("abcde", &letters, 2).unwrap_err();
+        assert_eq!(
+            failure.message,
+            "5 items can't be split in 2 compartments of the same size"
+        );
+        assert_eq!(
+            duplicates("ab1ab2", &letters, 3).unwrap_err().message,
+            "unknown item '1'"
+        );
+    }
+}
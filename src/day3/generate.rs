@@ -0,0 +1,69 @@
+use super::GROUP;
+use crate::{letterset::Alphabet, rng::Rng};
+
+/// Number of items in each compartment
+const COMPARTMENT: std::ops::RangeInclusive<u64> = 8..=16;
+
+/// Rucksacks of about `bytes` bytes, in groups of 3 sharing exactly one badge, each rucksack
+/// having exactly one item in both of its compartments
+pub fn generate(rng: &mut Rng, bytes: usize) -> String {
+    let alphabet = Alphabet::letters();
+    let letters: Vec<char> = (0..52).filter_map(|p| alphabet.symbol(p)).collect();
+    let mut text = String::with_capacity(bytes + 128);
+    while text.len() < bytes {
+        let mut pool = letters.clone();
+        shuffle(rng, &mut pool);
+        let badge = pool.pop().unwrap_or('a');
+        // every elf draws from its own letters, so that only the badge is shared
+        for own in pool.chunks(pool.len() / GROUP).take(GROUP) {
+            let duplicate = own[0];
+            let (first, second) = own[1..].split_at(own.len() / 2);
+            let len = (COMPARTMENT.start() + rng.below(COMPARTMENT.end() - COMPARTMENT.start() + 1))
+                as usize;
+            let mut left = vec![duplicate, badge];
+            left.extend((2..len).map(|_| first[rng.below(first.len() as u64) as usize]));
+            let mut right = vec![duplicate];
+            right.extend((1..len).map(|_| second[rng.below(second.len() as u64) as usize]));
+            shuffle(rng, &mut left);
+            shuffle(rng, &mut right);
+            text.extend(left);
+            text.extend(right);
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Fisher-Yates
+fn shuffle<T>(rng: &mut Rng, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::{
+        day3::{find_uneven_item, groups},
+        letterset::Alphabet,
+        rng::Rng,
+    };
+
+    #[test]
+    fn generated_rucksacks_are_valid() {
+        let letters = Alphabet::letters();
+        let text = generate(&mut Rng::new(3), 10_000);
+        assert!((10_000..10_200).contains(&text.len()));
+        assert_eq!(text, generate(&mut Rng::new(3), 10_000));
+
+        let groups = groups(&text, &letters, 3).unwrap();
+        assert!(groups.iter().all(|g| g.len() == 3 && g.badge().is_some()));
+        assert!(find_uneven_item(&text, &letters, 2).is_ok());
+        for line in text.lines() {
+            let (left, right) = line.split_at(line.len() / 2);
+            let common = letters.set(left).unwrap() & letters.set(right).unwrap();
+            assert_eq!(common.len(), 1, "{}", line);
+        }
+    }
+}
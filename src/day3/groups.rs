@@ -0,0 +1,165 @@
+use crate::{
+    foldby::FoldByExt,
+    letterset::{frequency, Alphabet, LetterSet},
+    Error, Result,
+};
+
+/// Consecutive rucksacks and the items they all carry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Group {
+    /// Line of the first rucksack of the group, 1 based
+    pub first: usize,
+    /// Line of the last rucksack of the group
+    pub last: usize,
+    pub shared: LetterSet,
+}
+
+impl Group {
+    const EMPTY: Group = Group {
+        first: 0,
+        last: 0,
+        shared: LetterSet::ALL,
+    };
+
+    fn add(self, (line, items): (usize, LetterSet)) -> Self {
+        Self {
+            first: if self.last == 0 { line } else { self.first },
+            last: line,
+            shared: self.shared & items,
+        }
+    }
+
+    /// Number of rucksacks of the group
+    pub fn len(&self) -> usize {
+        self.last + 1 - self.first
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.last == 0
+    }
+
+    /// The badge of the group: its only shared item
+    pub fn badge(&self) -> Option<u8> {
+        self.shared.single()
+    }
+
+    /// Why the group has no badge, such as `lines 4-6 share 2 items {a, b}`
+    pub fn describe(&self, alphabet: &Alphabet) -> String {
+        let shared = match self.shared.len() {
+            0 => "no item".to_string(),
+            1 => format!("1 item {}", alphabet.describe(self.shared)),
+            n => format!("{} items {}", n, alphabet.describe(self.shared)),
+        };
+        format!("lines {}-{} share {}", self.first, self.last, shared)
+    }
+}
+
+/// Split the rucksacks in groups of `size` consecutive lines, the last group may be smaller
+pub fn groups(input: &str, alphabet: &Alphabet, size: usize) -> Result<Vec<Group>> {
+    let rucksacks = input
+        .lines()
+        .map(|bag| alphabet.set(bag).map_err(|f| f.locate(input)))
+        .collect::<Result<Vec<LetterSet>>>()?;
+    group_sets(rucksacks, size)
+}
+
+/// Split the items of consecutive rucksacks in groups of `size`, the first rucksack on line 1
+pub fn group_sets(
+    rucksacks: impl IntoIterator<Item = LetterSet>,
+    size: usize,
+) -> Result<Vec<Group>> {
+    if size == 0 {
+        return Err(Error::unsolvable("a group holds at least one rucksack"));
+    }
+    Ok(rucksacks
+        .into_iter()
+        .enumerate()
+        .map(|(idx, items)| (idx + 1, items))
+        .fold_by(size, Group::EMPTY, Group::add)
+        .collect())
+}
+
+/// Sum of the priorities of the badges, failing on the first group that hasn't exactly one
+pub fn badge_priorities(groups: &[Group], alphabet: &Alphabet) -> Result<u32> {
+    groups
+        .iter()
+        .map(|group| {
+            group
+                .badge()
+                .map(|badge| badge as u32 + 1)
+                .ok_or_else(|| Error::unsolvable(group.describe(alphabet)))
+        })
+        .sum()
+}
+
+/// Number of groups sharing each item, most shared first then by priority
+pub fn badge_frequency(groups: &[Group]) -> Vec<(u8, usize)> {
+    frequency(groups.iter().map(|group| group.shared))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{badge_frequency, badge_priorities, groups, Group};
+    use crate::{letterset::Alphabet, Error};
+
+    const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn groups_keep_their_lines() {
+        let letters = Alphabet::letters();
+        let triplets = groups(INPUT, &letters, 3).unwrap();
+        assert_eq!(triplets.len(), 2);
+        assert_eq!((triplets[0].first, triplets[0].last), (1, 3));
+        assert_eq!((triplets[1].first, triplets[1].last), (4, 6));
+        assert_eq!(badge_priorities(&triplets, &letters), Ok(18 + 52));
+    }
+
+    #[test]
+    fn groups_of_any_size() {
+        let letters = Alphabet::letters();
+        let pairs = groups(INPUT, &letters, 2).unwrap();
+        assert_eq!(pairs.iter().map(Group::len).collect::<Vec<_>>(), [2, 2, 2]);
+        let fives = groups(INPUT, &letters, 5).unwrap();
+        assert_eq!(fives.iter().map(Group::len).collect::<Vec<_>>(), [5, 1]);
+        assert_eq!(fives[1].shared, letters.set("CrZsJPGzwLmpMD").unwrap());
+        assert_eq!(
+            groups(INPUT, &letters, 0),
+            Err(Error::unsolvable("a group holds at least one rucksack"))
+        );
+    }
+
+    #[test]
+    fn groups_without_a_single_badge_are_reported() {
+        let letters = Alphabet::letters();
+        let triplets = groups("abc\nbcd\ncde\nabc\nxyz\naaa\n", &letters, 3).unwrap();
+        assert_eq!(triplets[0].badge(), Some(2));
+        assert_eq!(triplets[1].badge(), None);
+        assert_eq!(
+            badge_priorities(&triplets, &letters),
+            Err(Error::unsolvable("lines 4-6 share no item"))
+        );
+
+        let triplets = groups("abc\nabd\nabe\n", &letters, 3).unwrap();
+        assert_eq!(
+            badge_priorities(&triplets, &letters),
+            Err(Error::unsolvable("lines 1-3 share 2 items {a, b}"))
+        );
+    }
+
+    #[test]
+    fn badges_are_counted() {
+        let letters = Alphabet::letters();
+        let triplets = groups("ab\nba\nZa\nZc\nZc\nZa\nb\nb\nb\n", &letters, 3).unwrap();
+        assert_eq!(badge_frequency(&triplets), vec![(0, 1), (1, 1), (51, 1)]);
+        let singles = groups("abc\ncZ\nZ\n", &letters, 1).unwrap();
+        assert_eq!(
+            badge_frequency(&singles),
+            vec![(2, 2), (51, 2), (0, 1), (1, 1)]
+        );
+    }
+}
@@ -0,0 +1,258 @@
+mod baseline;
+mod compartments;
+mod generate;
+mod groups;
+mod report;
+mod visualise;
+
+use std::hint::black_box;
+
+use baseline::compute_bitflags_for_string;
+pub use compartments::{duplicates, Compartments};
+pub use generate::generate;
+pub use groups::{badge_frequency, badge_priorities, group_sets, groups, Group};
+pub use report::report;
+pub use visualise::{render, visualise};
+
+use crate::{
+    error::Failure,
+    letterset::{letter_set, Alphabet, LetterSet},
+    solution::Kernel,
+    Result, Solution,
+};
+
+/// Number of compartments of a rucksack
+const COMPARTMENTS: usize = 2;
+/// Number of elves in a group sharing a badge
+const GROUP: usize = 3;
+
+pub struct Day3;
+
+/// A rucksack of the puzzle input, its items checked against the alphabet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    /// Every item of the rucksack
+    pub items: LetterSet,
+    /// Items found in more than one compartment
+    pub duplicates: LetterSet,
+}
+
+impl Rucksack {
+    /// Priority of the lowest duplicated item, 0 when nothing is duplicated
+    pub fn priority(&self) -> u32 {
+        self.duplicates.first().map_or(0, |p| p as u32 + 1)
+    }
+}
+
+/// Check every rucksack of `input` against `alphabet` and split it in `compartments`
+pub fn parse_rucksacks(
+    input: &str,
+    alphabet: &Alphabet,
+    compartments: usize,
+) -> Result<Vec<Rucksack>> {
+    input
+        .lines()
+        .map(|bag| {
+            let rucksack = Rucksack {
+                duplicates: duplicates(bag, alphabet, compartments)?,
+                items: alphabet.set(bag)?,
+            };
+            log::debug!(
+                "{:48}   {} -> prio:{:02}",
+                bag,
+                alphabet.describe(rucksack.duplicates),
+                rucksack.priority()
+            );
+            Ok(rucksack)
+        })
+        .collect::<std::result::Result<Vec<Rucksack>, Failure>>()
+        .map_err(|f| f.locate(input))
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_rucksacks(input, &Alphabet::letters(), COMPARTMENTS)
+    }
+
+    /// Sum of the priorities of the items found in both compartments
+    fn part1(rucksacks: &Self::Input) -> Result<Self::Answer1> {
+        Ok(sum_of_duplicates(rucksacks))
+    }
+
+    /// Sum of the priorities of the badges of each group of 3 elves
+    fn part2(rucksacks: &Self::Input) -> Result<Self::Answer2> {
+        let groups = group_sets(rucksacks.iter().map(|bag| bag.items), GROUP)?;
+        badge_priorities(&groups, &Alphabet::letters())
+    }
+
+    fn kernels() -> Vec<Kernel<Self::Input>> {
+        vec![
+            ("compute_bitflags_for_string", |input, _| {
+                input
+                    .lines()
+                    .for_each(|line| _ = black_box(compute_bitflags_for_string(line)))
+            }),
+            ("Alphabet::set_chars", |input, _| {
+                let letters = Alphabet::letters();
+                input
+                    .lines()
+                    .for_each(|line| _ = black_box(letters.set_chars(line)))
+            }),
+            ("letter_set", |input, _| {
+                input
+                    .lines()
+                    .for_each(|line| _ = black_box(letter_set(line)))
+            }),
+            ("ByteTable::set", |input, _| {
+                let letters = Alphabet::letters();
+                let table = letters.byte_table().unwrap();
+                input
+                    .lines()
+                    .for_each(|line| _ = black_box(table.set(line)))
+            }),
+            ("ByteTable::line_sets", |input, _| {
+                let letters = Alphabet::letters();
+                let table = letters.byte_table().unwrap();
+                table.line_sets(input).for_each(|set| _ = black_box(set))
+            }),
+        ]
+    }
+}
+
+/// Sum of the priorities of the badges of each group of `size` elves, items written in
+/// `alphabet`. Every group must share exactly one item.
+pub fn sum_of_badges(bags_string: &str, alphabet: &Alphabet, size: usize) -> Result<u32> {
+    badge_priorities(&groups(bags_string, alphabet, size)?, alphabet)
+}
+
+/// Sum of the priorities of the items found in several of the `compartments` of each
+/// rucksack, items written in `alphabet`. Only the lowest priority of a rucksack counts.
+pub fn find_uneven_item(
+    bags_string: &str,
+    alphabet: &Alphabet,
+    compartments: usize,
+) -> Result<u32> {
+    Ok(sum_of_duplicates(&parse_rucksacks(
+        bags_string,
+        alphabet,
+        compartments,
+    )?))
+}
+
+/// Sum of the priorities of the duplicated items of every rucksack
+pub fn sum_of_duplicates(rucksacks: &[Rucksack]) -> u32 {
+    let sum_of_diffs = rucksacks.iter().map(Rucksack::priority).sum();
+    log::info!("Sum of differences = {}", sum_of_diffs);
+    sum_of_diffs
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_uneven_item, sum_of_badges, Day3};
+    use crate::{letterset::Alphabet, Error, Solution};
+
+    #[test]
+    fn test_example_data_part1() {
+        const INPUT: &str = "
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+        assert_eq!(find_uneven_item(INPUT, &Alphabet::letters(), 2), Ok(157));
+    }
+
+    #[test]
+    fn test_example_data_part2() {
+        const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+        assert_eq!(Day3::part2(&Day3::parse(INPUT).unwrap()), Ok(70));
+    }
+
+    #[test]
+    fn odd_rucksacks_are_reported() {
+        const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp\nabc";
+        assert_eq!(
+            find_uneven_item(INPUT, &Alphabet::letters(), 2),
+            Err(Error::parse(
+                2,
+                1,
+                "3 items can't be split in 2 compartments of the same size"
+            ))
+        );
+    }
+
+    #[test]
+    fn unknown_items_are_located() {
+        const INPUT: &str = "abca
+ab1b
+";
+        let letters = Alphabet::letters();
+        assert_eq!(
+            find_uneven_item(INPUT, &letters, 2),
+            Err(Error::parse(2, 3, "unknown item '1'"))
+        );
+        assert_eq!(
+            sum_of_badges(INPUT, &letters, 3),
+            Err(Error::parse(2, 3, "unknown item '1'"))
+        );
+    }
+
+    #[test]
+    fn rucksacks_are_checked_when_parsed() {
+        assert_eq!(
+            Day3::parse("abca\nab1b\n"),
+            Err(Error::parse(2, 3, "unknown item '1'"))
+        );
+        assert_eq!(
+            Day3::parse("abca\nabc\n"),
+            Err(Error::parse(
+                2,
+                1,
+                "3 items can't be split in 2 compartments of the same size"
+            ))
+        );
+        let rucksacks = Day3::parse("abca\nabcb\n").unwrap();
+        assert_eq!(rucksacks.len(), 2);
+        assert_eq!(Day3::part1(&rucksacks), Ok(1 + 2));
+    }
+
+    #[test]
+    fn other_alphabets() {
+        let digits = Alphabet::digits();
+        // 2 has priority 3, 9 has priority 10, 3 has priority 4
+        assert_eq!(find_uneven_item("1232\n0919\n", &digits, 2), Ok(3 + 10));
+        assert_eq!(sum_of_badges("123\n345\n536\n", &digits, 3), Ok(4));
+
+        let runes = Alphabet::parse("ᚠᚢᚦᚨᚱ").unwrap();
+        assert_eq!(find_uneven_item("ᚠᚱᚢᚱ\n", &runes, 2), Ok(5));
+    }
+
+    #[test]
+    fn more_compartments() {
+        let letters = Alphabet::letters();
+        // c and then a are in two compartments
+        assert_eq!(find_uneven_item("abcdac\nxaybza\n", &letters, 3), Ok(1 + 1));
+        assert_eq!(
+            find_uneven_item("abcdac\nxaybz\n", &letters, 3),
+            Err(Error::parse(
+                2,
+                1,
+                "5 items can't be split in 3 compartments of the same size"
+            ))
+        );
+    }
+}
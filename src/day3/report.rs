@@ -0,0 +1,101 @@
+use super::{badge_frequency, groups, Group, GROUP};
+use crate::{letterset::Alphabet, report::Format, Result};
+
+/// How the rucksacks share their items in groups of 3: the groups without exactly one badge
+/// and how many groups share each item, rendered in `format`
+pub fn report(input: &str, format: Format) -> Result<String> {
+    let letters = Alphabet::letters();
+    let groups = groups(input, &letters, GROUP)?;
+    let rucksacks: usize = groups.iter().map(Group::len).sum();
+    let without_badge: Vec<&Group> = groups.iter().filter(|g| g.badge().is_none()).collect();
+    let frequency = badge_frequency(&groups);
+    let symbol = |item: u8| letters.symbol(item).unwrap_or('?');
+
+    Ok(match format {
+        Format::Text => {
+            let mut text = format!(
+                "{} rucksacks in {} groups of {}, {} without a single badge\n",
+                rucksacks,
+                groups.len(),
+                GROUP,
+                without_badge.len()
+            );
+            for group in &without_badge {
+                text += &format!("  {}\n", group.describe(&letters));
+            }
+            text += "\nitem  priority  groups\n";
+            let widest = frequency.first().map_or(1, |&(_, count)| count);
+            for &(item, count) in &frequency {
+                text += &format!(
+                    "{:>4}  {:>8}  {:>6} {}\n",
+                    symbol(item),
+                    item as u32 + 1,
+                    count,
+                    "#".repeat((count * 40).div_ceil(widest))
+                );
+            }
+            text
+        }
+        Format::Json => format!(
+            "{{\"rucksacks\":{},\"group_size\":{},\"groups\":{},\"without_badge\":[{}],\"badges\":[{}]}}",
+            rucksacks,
+            GROUP,
+            groups.len(),
+            without_badge
+                .iter()
+                .map(|g| format!(
+                    "{{\"first\":{},\"last\":{},\"shared\":[{}]}}",
+                    g.first,
+                    g.last,
+                    g.shared
+                        .iter()
+                        .map(|item| format!("\"{}\"", symbol(item)))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ))
+                .collect::<Vec<_>>()
+                .join(","),
+            frequency
+                .iter()
+                .map(|&(item, count)| format!(
+                    "{{\"item\":\"{}\",\"priority\":{},\"groups\":{}}}",
+                    symbol(item),
+                    item as u32 + 1,
+                    count
+                ))
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::report;
+    use crate::report::Format;
+
+    #[test]
+    fn example_report() {
+        const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+abc
+abd
+xyz
+";
+        let text = report(INPUT, Format::Text).unwrap();
+        assert!(text.starts_with("9 rucksacks in 3 groups of 3, 1 without a single badge\n"));
+        assert!(text.contains("  lines 7-9 share no item\n"));
+        assert!(text.contains("\n   r        18       1 ####"));
+        assert!(text.contains("\n   Z        52       1 ####"));
+
+        let json = report("abc\nabd\nabe\nabz\n", Format::Json).unwrap();
+        assert_eq!(
+            json,
+            "{\"rucksacks\":4,\"group_size\":3,\"groups\":2,\"without_badge\":[{\"first\":1,\"last\":3,\"shared\":[\"a\",\"b\"]},{\"first\":4,\"last\":4,\"shared\":[\"a\",\"b\",\"z\"]}],\"badges\":[{\"item\":\"a\",\"priority\":1,\"groups\":2},{\"item\":\"b\",\"priority\":2,\"groups\":2},{\"item\":\"z\",\"priority\":26,\"groups\":1}]}"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use super::{duplicates, Compartments, COMPARTMENTS};
+use crate::{
+    letterset::{frequency, Alphabet, LetterSet},
+    report::Style,
+    Result,
+};
+
+/// Number of items listed in the summary
+const MOST_DUPLICATED: usize = 10;
+
+/// Every rucksack with its duplicated items highlighted and the bitmaps of its compartments
+pub fn visualise(input: &str, style: Style) -> Result<String> {
+    render(input, &Alphabet::letters(), COMPARTMENTS, style)
+}
+
+/// Draw the rucksacks of `input`, split in `count` compartments, followed by the items
+/// duplicated in most rucksacks. Plain drawings mark the duplicated items with `^` under the
+/// rucksack and `*` in the bitmaps.
+pub fn render(input: &str, alphabet: &Alphabet, count: usize, style: Style) -> Result<String> {
+    let symbols: String = (0..alphabet.len())
+        .filter_map(|p| alphabet.symbol(p as u8))
+        .collect();
+    let mut text = format!("{:6}{}\n", "", vec![symbols; count].join("  "));
+    let mut all_duplicates = vec![];
+    for (idx, bag) in input.lines().enumerate() {
+        let shared = duplicates(bag, alphabet, count).map_err(|f| f.locate(input))?;
+        // the rucksack is valid once its duplicates are known
+        let compartments = bag.compartments(count).unwrap_or_default();
+        let sets: Vec<LetterSet> = compartments
+            .iter()
+            .map(|c| alphabet.set(c).unwrap_or_default())
+            .collect();
+        let is_shared = |c: char| alphabet.position(c).is_some_and(|p| shared.contains(p));
+
+        let items: Vec<String> = compartments
+            .iter()
+            .map(|c| {
+                c.chars()
+                    .map(|c| {
+                        if is_shared(c) {
+                            style.highlight(&c.to_string())
+                        } else {
+                            c.to_string()
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        text += &format!(
+            "\n{:>4}  {}  {}\n",
+            idx + 1,
+            items.join(" "),
+            alphabet.describe(shared)
+        );
+        if style == Style::Plain && !shared.is_empty() {
+            let markers: Vec<String> = compartments
+                .iter()
+                .map(|c| {
+                    c.chars()
+                        .map(|c| if is_shared(c) { '^' } else { ' ' })
+                        .collect()
+                })
+                .collect();
+            text += &format!("{:6}{}\n", "", markers.join(" ").trim_end());
+        }
+        let bitmaps: Vec<String> = sets
+            .iter()
+            .map(|set| bitmap(*set, shared, alphabet.len(), style))
+            .collect();
+        text += &format!("{:6}{}\n", "", bitmaps.join("  "));
+        all_duplicates.push(shared);
+    }
+
+    text += "\nmost duplicated items\nitem  priority  rucksacks\n";
+    for (item, rucksacks) in frequency(all_duplicates).into_iter().take(MOST_DUPLICATED) {
+        text += &format!(
+            "{:>4}  {:>8}  {:>9}\n",
+            alphabet.symbol(item).unwrap_or('?'),
+            item as u32 + 1,
+            rucksacks
+        );
+    }
+    Ok(text)
+}
+
+/// One character per item of the alphabet: `.` when absent from `set`, `#` when present,
+/// highlighted or `*` when shared
+fn bitmap(set: LetterSet, shared: LetterSet, len: usize, style: Style) -> String {
+    (0..len)
+        .map(|p| p as u8)
+        .map(|p| match (set.contains(p), shared.contains(p), style) {
+            (false, _, _) => ".".to_string(),
+            (true, false, _) => "#".to_string(),
+            (true, true, Style::Plain) => "*".to_string(),
+            (true, true, Style::Ansi) => style.highlight("#"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{render, visualise};
+    use crate::{letterset::Alphabet, report::Style, Error};
+
+    #[test]
+    fn plain_rucksacks() {
+        let text = render("abcabd\naabb\n", &Alphabet::letters(), 2, Style::Plain).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0].len(), 6 + 52 * 2 + 2);
+        assert_eq!(lines[2], "   1  abc abd  {a, b}");
+        assert_eq!(lines[3], "      ^^  ^^");
+        assert_eq!(&lines[4][..12], "      **#...");
+        assert_eq!(&lines[4][60..64], "**.#");
+        // nothing shared, no markers
+        assert_eq!(lines[6], "   2  aa bb  {}");
+        assert_eq!(&lines[7][..9], "      #..");
+        assert!(text.ends_with(
+            "item  priority  rucksacks\n   a         1          1\n   b         2          1\n"
+        ));
+    }
+
+    #[test]
+    fn ansi_rucksacks() {
+        let text = visualise("vJrwpWtwJgWrhcsFMMfFFhFp\n", Style::Ansi).unwrap();
+        assert!(text.contains("vJrw\x1b[1;31mp\x1b[0mWtwJgWr hcsFMMfFFhF\x1b[1;31mp\x1b[0m  {p}"));
+        assert!(!text.contains('^'));
+        assert!(!text.contains('*'));
+    }
+
+    #[test]
+    fn alphabets_of_256_symbols() {
+        let symbols: String = (0..256u32)
+            .filter_map(|c| char::from_u32(0x100 + c))
+            .collect();
+        let alphabet = Alphabet::parse(&symbols).unwrap();
+        assert_eq!(alphabet.len(), 256);
+        let [first, second, last] = [0, 1, 255].map(|p| alphabet.symbol(p).unwrap());
+        let bag: String = [first, last, last, second].iter().collect();
+        let text = render(&bag, &alphabet, 2, Style::Plain).unwrap();
+        assert_eq!(
+            text.lines().nth(4).unwrap(),
+            format!("      #{}*  .#{}*", ".".repeat(254), ".".repeat(253))
+        );
+    }
+
+    #[test]
+    fn more_compartments_and_errors() {
+        let text = render("abcdac\n", &Alphabet::letters(), 3, Style::Plain).unwrap();
+        assert!(text.contains("   1  ab cd ac  {a, c}\n      ^  ^  ^^\n"));
+        assert_eq!(
+            visualise("abc\n", Style::Plain),
+            Err(Error::parse(
+                1,
+                1,
+                "3 items can't be split in 2 compartments of the same size"
+            ))
+        );
+    }
+}
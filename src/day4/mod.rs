@@ -0,0 +1,160 @@
+use std::hint::black_box;
+
+use crate::{
+    error::Failure,
+    interval::{Interval, IntervalSet},
+    parse::{map, number, parse_all, separated_list, tag, terminated, try_map, PResult},
+    solution::Kernel,
+    Result, Solution,
+};
+
+fn trace_items<I: std::fmt::Debug>(item: I) -> I {
+    log::trace!("{:?}: {:?}", std::any::type_name::<I>(), item);
+    item
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<ElvesTeam>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .trim()
+            .lines()
+            .map(trace_items)
+            .map(|line| ElvesTeam::parse(line).map_err(|f| f.locate(input)))
+            .map(trace_items)
+            .collect()
+    }
+
+    /// Teams in which an assignment fully contains another one
+    fn part1(teams: &Self::Input) -> Result<Self::Answer1> {
+        Ok(count_teams_having_complete_taks_inclusion(teams))
+    }
+
+    /// Teams in which assignments overlap
+    fn part2(teams: &Self::Input) -> Result<Self::Answer2> {
+        Ok(count_teams_having_overlaping_tasks(teams))
+    }
+
+    fn kernels() -> Vec<Kernel<Self::Input>> {
+        vec![("has_assign_overlaps", |_, teams| {
+            teams
+                .iter()
+                .for_each(|team| _ = black_box(team.has_assign_overlaps()))
+        })]
+    }
+}
+
+fn count_teams_having_complete_taks_inclusion(teams: &[ElvesTeam]) -> usize {
+    teams
+        .iter()
+        .map(|team| if team.has_assign_inclusion() { 1 } else { 0 })
+        .map(trace_items)
+        .sum::<usize>()
+}
+
+fn count_teams_having_overlaping_tasks(teams: &[ElvesTeam]) -> usize {
+    teams
+        .iter()
+        .map(|team| if team.has_assign_overlaps() { 1 } else { 0 })
+        .map(trace_items)
+        .sum::<usize>()
+}
+
+/// Sections assigned to an elf
+type Assignment = Interval<usize>;
+
+/// The failure of an assignment ending before it starts points at its end
+fn parse_assignment(s: &str) -> PResult<'_, Assignment> {
+    let (rest, start) = terminated(number(), tag("-"))(s)?;
+    try_map(number(), move |end| {
+        Interval::new(start, end).ok_or("an assignment can't end before it starts")
+    })(rest)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ElvesTeam {
+    members: Vec<Assignment>,
+}
+
+impl ElvesTeam {
+    fn parse(s: &str) -> std::result::Result<Self, Failure<'_>> {
+        parse_all(
+            map(separated_list(parse_assignment, tag(",")), |members| Self {
+                members,
+            }),
+            s,
+        )
+    }
+
+    // useless cross product here -> TODO: take only the triangle
+    fn has_assign_overlaps(&self) -> bool {
+        self.members
+            .iter()
+            .enumerate()
+            .fold(false, |acc, (i, left)| {
+                acc || self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .fold(false, |acc, (j, right)| {
+                        if i == j {
+                            acc
+                        } else {
+                            let overlaps = left.overlaps(right);
+                            log::debug!(
+                                "Does {:?} overlaps {:?} ? The anwser is {}",
+                                left,
+                                right,
+                                overlaps
+                            );
+                            acc || overlaps
+                        }
+                    })
+            })
+    }
+
+    /// Sections between the first and the last assigned ones that nobody in the team covers
+    pub fn uncovered(&self) -> IntervalSet<usize> {
+        self.members
+            .iter()
+            .copied()
+            .collect::<IntervalSet<_>>()
+            .gaps()
+    }
+
+    fn has_assign_inclusion(&self) -> bool {
+        self.members
+            .iter()
+            .enumerate()
+            .fold(false, |acc, (i, left)| {
+                acc || self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .fold(false, |acc, (j, right)| {
+                        if i == j {
+                            acc
+                        } else {
+                            let includes = left.includes(right);
+                            log::debug!(
+                                "Does {:?} includes {:?} ? The anwser is {}",
+                                left,
+                                right,
+                                includes
+                            );
+                            acc || includes
+                        }
+                    })
+            })
+    }
+}
+
+#[cfg(test)]
+mod test;
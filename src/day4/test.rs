@@ -1,32 +1,27 @@
-use crate::{
-    count_teams_having_complete_taks_inclusion, count_teams_having_overlaping_tasks, Assignment,
+use super::{
+    count_teams_having_complete_taks_inclusion, count_teams_having_overlaping_tasks, Day4,
     ElvesTeam,
 };
+use crate::{interval::Interval, Error, Solution};
 
 #[test]
 fn parse_team() {
     assert_eq!(
         ElvesTeam::parse("1-4,2-6").unwrap(),
         ElvesTeam {
-            members: vec![Assignment(1, 4), Assignment(2, 6)]
+            members: vec![Interval::new(1, 4).unwrap(), Interval::new(2, 6).unwrap()]
         }
     );
 }
 
 #[test]
 fn the_same_task_overlaps() {
-    assert_eq!(
-        ElvesTeam::parse("1-1,1-1").unwrap().has_assign_overlaps(),
-        true
-    );
+    assert!(ElvesTeam::parse("1-1,1-1").unwrap().has_assign_overlaps());
 }
 
 #[test]
 fn the_same_tasks_overlaps() {
-    assert_eq!(
-        ElvesTeam::parse("1-2,1-2").unwrap().has_assign_overlaps(),
-        true
-    );
+    assert!(ElvesTeam::parse("1-2,1-2").unwrap().has_assign_overlaps());
 }
 
 #[test]
@@ -38,7 +33,7 @@ fn validate_with_example() {
 2-8,3-7
 6-6,4-6
 2-6,4-8";
-    let sum = count_teams_having_complete_taks_inclusion(INPUT);
+    let sum = count_teams_having_complete_taks_inclusion(&Day4::parse(INPUT).unwrap());
     assert_eq!(sum, 2);
 }
 
@@ -52,7 +47,7 @@ fn validate_with_example_2() {
 2-8,3-7
 6-6,4-6
 2-6,4-8";
-    let sum = count_teams_having_overlaping_tasks(INPUT);
+    let sum = count_teams_having_overlaping_tasks(&Day4::parse(INPUT).unwrap());
     assert_eq!(sum, 4);
 }
 
@@ -77,6 +72,33 @@ fn validate_with_example_2_2() {
 77-77,40-77
 28-70,28-70
 4-24,2-4";
-    let sum = count_teams_having_overlaping_tasks(INPUT);
+    let sum = count_teams_having_overlaping_tasks(&Day4::parse(INPUT).unwrap());
     assert_eq!(sum, 11);
 }
+
+#[test]
+fn malformed_assignments_are_located() {
+    assert_eq!(
+        Day4::parse("2-4,6-8\n2-3,4_5\n").err(),
+        Some(Error::parse(2, 6, "expected \"-\""))
+    );
+    assert_eq!(
+        Day4::parse("2-4,6-8\n2-3,4-x5\n").err(),
+        Some(Error::parse(2, 7, "expected a number"))
+    );
+    assert_eq!(
+        Day4::parse("2-4,8-6\n").err(),
+        Some(Error::parse(
+            1,
+            7,
+            "an assignment can't end before it starts"
+        ))
+    );
+}
+
+#[test]
+fn sections_covered_by_nobody() {
+    let team = ElvesTeam::parse("2-4,12-15,6-8,3-5").unwrap();
+    assert_eq!(team.uncovered().to_string(), "{9-11}");
+    assert!(ElvesTeam::parse("2-4,5-7").unwrap().uncovered().is_empty());
+}
@@ -51,7 +51,7 @@ impl TryFromChar for usize {
             'd' => Ok(13),
             'e' => Ok(14),
             'f' => Ok(15),
-            e @ _ => Err(format!("Impossible to convert {} to usize", e)),
+            e => Err(format!("Impossible to convert {} to usize", e)),
         }
     }
 }
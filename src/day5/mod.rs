@@ -0,0 +1,66 @@
+mod charables;
+mod parse;
+mod ship;
+
+#[cfg(test)]
+mod test;
+
+use crate::{error::Failure, parse::whitespace, Result, Solution};
+use ship::{CrateMover, Ship};
+
+use parse::instruction::Instruction;
+
+pub struct Day5;
+
+/// The ship as drawn in the puzzle input, and the crane instructions to execute on it
+pub struct Procedure {
+    ship: Ship<char>,
+    instructions: Vec<Instruction>,
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let locate = |f: Failure| f.locate(input);
+        let (rest, ship) = Ship::<char>::parse(input).map_err(locate)?;
+        log::debug!("\n{}", ship);
+
+        let (mut rest, _) = whitespace(rest).map_err(locate)?;
+
+        let mut instructions = vec![];
+        while !rest.is_empty() {
+            let instruction;
+
+            (rest, instruction) = Instruction::parse(rest).map_err(locate)?;
+            instructions.push(instruction);
+            (rest, _) = whitespace(rest).map_err(locate)?;
+        }
+        Ok(Procedure { ship, instructions })
+    }
+
+    /// The crane moves crates one at a time
+    fn part1(procedure: &Self::Input) -> Result<Self::Answer1> {
+        rearrange(procedure, CrateMover::Model9000)
+    }
+
+    /// The crane moves several crates at once
+    fn part2(procedure: &Self::Input) -> Result<Self::Answer2> {
+        rearrange(procedure, CrateMover::Model9001)
+    }
+}
+
+fn rearrange(procedure: &Procedure, crane: CrateMover) -> Result<String> {
+    let mut ship = procedure.ship.clone();
+    for instruction in &procedure.instructions {
+        log::trace!("{:?}", instruction);
+        ship.execute(instruction, crane)?;
+    }
+
+    log::debug!("\n{}", ship);
+    Ok(ship.tops_values_as_string())
+}
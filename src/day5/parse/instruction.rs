@@ -1,3 +1,8 @@
+use crate::{
+    error::Failure,
+    parse::{alt, map, number, opt, tag, terminated, PResult},
+};
+
 #[derive(Debug, PartialEq)]
 pub struct Instruction {
     pub(crate) by: usize,
@@ -11,12 +16,8 @@ enum Action {
 }
 
 impl Action {
-    fn parse<'s>(input: &'s str) -> Result<(&'s str, Self), String> {
-        let (line, rest) = input.split_once(' ').unwrap_or((input, ""));
-        match line {
-            "move" => Ok((rest, Action::Move)),
-            _ => Err(format!("unknown action <{}>", line)),
-        }
+    fn parse(input: &str) -> PResult<'_, Self> {
+        map(terminated(tag("move"), tag(" ")), |_| Action::Move)(input)
     }
 }
 
@@ -27,34 +28,19 @@ enum Dir {
 }
 
 impl Dir {
-    fn parse<'s>(input: &'s str) -> Result<(&'s str, Self), String> {
-        let (word, rest) = input.split_once(' ').unwrap_or((input, ""));
-        match word {
-            "from" => Ok((rest, Self::From)),
-            "to" => Ok((rest, Self::To)),
-            _ => Err("unknown direction".to_string()),
-        }
+    fn parse(input: &str) -> PResult<'_, Self> {
+        terminated(
+            alt(
+                map(tag("from"), |_| Self::From),
+                map(tag("to"), |_| Self::To),
+            ),
+            tag(" "),
+        )(input)
     }
 }
 
-pub fn parse_usize<'s>(input: &'s str) -> Result<(&'s str, usize), String> {
-    parse_u64(input).map(|(rest, value)| (rest, value as usize))
-}
-
-fn parse_u64<'s>(input: &'s str) -> Result<(&'s str, u64), String> {
-    let (nb_str_len, value) = input
-        .chars()
-        .take_while(|c| c.is_digit(10))
-        .map(|c| c.to_digit(10).expect("invalid digit") as u64)
-        .enumerate()
-        .fold((0_usize, 0_u64), |(_, total), (index, digit)| {
-            (index + 1, total * 10 + digit)
-        });
-    if input.chars().nth(nb_str_len) == Some(' ') {
-        Ok((&input[(nb_str_len + 1)..], value))
-    } else {
-        Ok((&input[nb_str_len..], value))
-    }
+pub fn parse_usize(input: &str) -> PResult<'_, usize> {
+    terminated(number(), opt(tag(" ")))(input)
 }
 
 impl Instruction {
@@ -66,7 +52,7 @@ impl Instruction {
         }
     }
 
-    pub fn parse<'s>(input: &'s str) -> Result<(&'s str, Self), String> {
+    pub fn parse(input: &str) -> PResult<'_, Self> {
         let mut from = None;
         let mut to = None;
         let mut set_dir_val = |dir, val| {
@@ -86,8 +72,8 @@ impl Instruction {
         let (rest, value) = parse_usize(rest)?;
         set_dir_val(dir, value);
 
-        let from = from.ok_or("no source value provided".to_string())?;
-        let to = to.ok_or("no destination value provided".to_string())?;
+        let from = from.ok_or_else(|| Failure::new(input, "no source value provided"))?;
+        let to = to.ok_or_else(|| Failure::new(input, "no destination value provided"))?;
 
         Ok((rest, Instruction::new(qty, from, to)))
     }
@@ -1,4 +1,11 @@
-use crate::{charables::TryFromChar, ship::Ship};
+use crate::{
+    day5::{charables::TryFromChar, ship::Ship},
+    error::Failure,
+    parse::{
+        alt, any_char, map, parse_all, preceded, satisfy, separated_list, tag, terminated, try_map,
+        PResult,
+    },
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Cell<T> {
@@ -22,26 +29,38 @@ where
         matches!(self, Cell::Index(_))
     }
 
-    fn parse<'s>(s: &'s str) -> Result<(&'s str, Cell<T>), &'s str> {
-        log::debug!("parse cell : {} ... ({})", &s[0..3], s.len());
-        if s.len() < 3 {
-            Err(s)
-        } else {
-            let (cell, rem) = s.split_at(3);
-            let mut chrs = cell.chars();
-            let c1 = chrs.next().unwrap();
-            let c2 = chrs.next().unwrap();
-            let c3 = chrs.next().unwrap();
-            match (c1, c2, c3) {
-                (' ', ' ', ' ') => Ok((rem, Cell::Empty)),
-                ('[', c, ']') => {
-                    let t = T::try_from_char(c).map_err(|_| "try_from_char failed")?;
-                    Ok((rem, Cell::Value(t)))
-                }
-                (' ', n, ' ') if n.is_numeric() => Ok((rem, Cell::Index(n as u8 - '0' as u8))),
-                _ => Err(cell),
+    fn parse(s: &str) -> PResult<'_, Cell<T>> {
+        log::debug!(
+            "parse cell : {} ... ({})",
+            s.get(0..3).unwrap_or(s),
+            s.len()
+        );
+        let empty = map(tag("   "), |_| Cell::Empty);
+        let value = try_map(preceded(tag("["), terminated(any_char, tag("]"))), |c| {
+            T::try_from_char(c)
+                .map(Cell::Value)
+                .map_err(|_| format!("{:?} isn't a valid crate", c))
+        });
+        let index = map(
+            preceded(
+                tag(" "),
+                terminated(
+                    satisfy("a stack index", |c| ('1'..='9').contains(&c)),
+                    tag(" "),
+                ),
+            ),
+            |n| Cell::Index(n as u8 - b'0'),
+        );
+        alt(alt(empty, value), index)(s).map_err(|failure| {
+            if failure.at.len() == s.len() {
+                Failure::new(
+                    s,
+                    "expected a crate `[x]`, a stack index ` n ` or an empty cell",
+                )
+            } else {
+                failure
             }
-        }
+        })
     }
 }
 
@@ -56,48 +75,30 @@ impl<T> Line<T>
 where
     T: TryFromChar,
 {
-    fn parse<'l>(input: &'l str) -> Result<(&'l str, Line<T>), &'l str> {
-        let (mut line, rest) = input.split_once('\n').unwrap_or((input, ""));
+    fn parse(input: &str) -> PResult<'_, Line<T>> {
+        let (line, rest) = input.split_once('\n').unwrap_or((input, ""));
+        let line = line.strip_suffix('\r').unwrap_or(line);
         log::debug!("parse line : {} ... ({})", line, line.len());
-        match line.len() {
-            0 => Ok((&rest, Line::Empty)),
-            n if (n + 1) % 3 == 0 => {
-                let mut cells = Vec::with_capacity(n);
-                let mut line_of_indexes: Option<bool> = None;
-                loop {
-                    let cell: Cell<T>;
-                    (line, cell) = Cell::parse(line)?;
-
-                    if !check_consistency(&mut line_of_indexes, cell.is_index()) {
-                        return Err(" both indexes and values found");
-                    }
-
-                    cells.push(cell);
+        if line.is_empty() {
+            return Ok((rest, Line::Empty));
+        }
+        let cells = parse_all(separated_list(Cell::parse, tag(" ")), line)?;
 
-                    match line.chars().nth(0) {
-                        Some(' ') => {
-                            log::trace!(target: "line", "continue with <{}>", line);
-                            line = &line[1..];
-                        }
-                        _ => {
-                            break;
-                        }
-                    }
-                }
-                Ok((
-                    rest,
-                    if line_of_indexes.unwrap() {
-                        Line::Abscissa(cells)
-                    } else {
-                        Line::Content(cells)
-                    },
-                ))
-            }
-            _ => {
-                log::error!("Malformed line of len={} : {} ", line.len(), line);
-                Err("malformed line")
-            }
+        let mut line_of_indexes: Option<bool> = None;
+        if !cells
+            .iter()
+            .all(|cell| check_consistency(&mut line_of_indexes, cell.is_index()))
+        {
+            return Err(Failure::new(line, "both indexes and values found"));
         }
+        Ok((
+            rest,
+            if line_of_indexes == Some(true) {
+                Line::Abscissa(cells)
+            } else {
+                Line::Content(cells)
+            },
+        ))
     }
 }
 
@@ -113,20 +114,23 @@ impl<T> Ship<T>
 where
     T: TryFromChar + Clone,
 {
-    pub fn parse<'s>(s: &'s str) -> Result<(&'s str, Ship<T>), &'s str> {
+    pub fn parse(s: &str) -> PResult<'_, Ship<T>> {
         log::debug!("parse tank : {} ... ({})", s, s.len());
-        //let width = None;
         let mut rest = s;
         let mut content = vec![];
         let mut width = None;
         loop {
+            let line = rest;
             let cells: Line<T>;
             (rest, cells) = Line::parse(rest)?;
             match cells {
                 Line::Content(c) => {
                     log::debug!("parse tank : content found");
                     if !check_consistency(&mut width, c.len()) {
-                        return Err("Varrying width of ship stacks detected :(");
+                        return Err(Failure::new(
+                            line,
+                            "Varrying width of ship stacks detected :(",
+                        ));
                     }
                     let values = c
                         .into_iter()
@@ -136,26 +140,27 @@ where
                 }
                 Line::Abscissa(indexes) => {
                     log::debug!("parse tank : indexes found");
-                    assert_eq!(indexes.len(), width.unwrap());
-                    assert!(
-                        indexes
-                            .iter()
-                            .enumerate()
-                            .map(|(i, v)| {
-                                let idx = (i + 1) as u8;
-                                matches!(v, Cell::Index(idx))
-                            }) // v == &Cell::Index((i + 1) as u8))
-                            .fold(true, |acc, ok| acc && ok),
-                        "indexes aren't following each others"
-                    );
+                    if Some(indexes.len()) != width {
+                        return Err(Failure::new(
+                            line,
+                            format!("{} indexes for {:?} stacks", indexes.len(), width),
+                        ));
+                    }
+                    if !indexes
+                        .iter()
+                        .enumerate()
+                        .all(|(i, v)| matches!(v, Cell::Index(idx) if *idx as usize == i + 1))
+                    {
+                        return Err(Failure::new(line, "indexes aren't following each others"));
+                    }
                     break;
                 }
-                _ => {
-                    return Err("Line Cells error");
+                Line::Empty => {
+                    return Err(Failure::new(line, "expected the stack indexes line"));
                 }
             }
         }
-        let width = width.unwrap();
+        let width = width.unwrap_or_default();
 
         let mut ship = Ship::new_empty_ship(width);
 
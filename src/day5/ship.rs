@@ -1,12 +1,23 @@
 use std::{borrow::Cow, fmt::Display, str::FromStr};
 
-use crate::{
+use super::{
     charables::{ToChar, TryFromChar},
     parse::instruction::Instruction,
 };
+use crate::{Error, Result};
 
 pub type Stack<T> = Vec<T>;
 
+/// The crane used to execute the instructions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CrateMover {
+    /// moves crates one at a time (day5 part 1)
+    Model9000,
+    /// moves several crates at once, keeping their order (day5 part 2)
+    Model9001,
+}
+
+#[derive(Clone)]
 pub struct Ship<T> {
     internal: Vec<Stack<T>>,
 }
@@ -15,12 +26,12 @@ impl<T> FromStr for Ship<T>
 where
     T: TryFromChar + Clone,
 {
-    type Err = String;
+    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_str(s: &str) -> Result<Self> {
         Ship::parse(s)
             .map(|(_, ship)| ship)
-            .map_err(|s| s.to_string())
+            .map_err(|failure| failure.locate(s))
     }
 }
 
@@ -46,25 +57,33 @@ impl<T> Ship<T> {
         self
     }
 
-    pub fn execute(&mut self, inst: Instruction) {
+    pub fn execute(&mut self, inst: &Instruction, crane: CrateMover) -> Result<()> {
         let machine_idx = |human_idx| human_idx - 1;
-        /* day5 part 1
-        for _ in 0..inst.by {
-            let pop = self.internal[machine_idx(inst.from)].pop();
-            pop.map(|v| self.internal[machine_idx(inst.to)].push(v));
+        let width = self.internal.len();
+        if let Some(stack) = [inst.from, inst.to]
+            .into_iter()
+            .find(|&stack| stack == 0 || stack > width)
+        {
+            return Err(Error::unsolvable(format!(
+                "{:?} has no stack {}, the ship has {} stacks",
+                inst, stack, width
+            )));
         }
-        */
         let src_len = self.internal[machine_idx(inst.from)].len();
-        assert!(
-            src_len >= inst.by,
-            "Crane 9001 trying to lift {} whereas there are only {}",
-            inst.by,
-            src_len
-        );
-        let poped_vec = self.internal[machine_idx(inst.from)].split_off(src_len - inst.by);
+        if src_len < inst.by {
+            return Err(Error::unsolvable(format!(
+                "{:?} trying to lift {} whereas there are only {}",
+                crane, inst.by, src_len
+            )));
+        }
+        let mut poped_vec = self.internal[machine_idx(inst.from)].split_off(src_len - inst.by);
+        if crane == CrateMover::Model9000 {
+            poped_vec.reverse();
+        }
         poped_vec
             .into_iter()
             .for_each(|elt| self.internal[machine_idx(inst.to)].push(elt));
+        Ok(())
     }
 }
 
@@ -75,11 +94,8 @@ where
     pub fn tops_values_as_string(&self) -> String {
         self.internal
             .iter()
-            .map(|vector| vector.last().map(T::to_char))
-            .filter(Option::is_some)
-            .fold("".to_string(), |acc, cur| {
-                format!("{}{}", acc, cur.unwrap())
-            })
+            .filter_map(|vector| vector.last().map(T::to_char))
+            .collect()
     }
 }
 
@@ -89,7 +105,9 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let highest_stack_size = |ship: &Self| ship.internal.iter().map(|s| s.len()).max();
-        let Some(height) = highest_stack_size(self) else { return write!(f, "\n - the ship is empty - \n"); };
+        let Some(height) = highest_stack_size(self) else {
+            return write!(f, "\n - the ship is empty - \n");
+        };
         let width = self.internal.len();
 
         let get_repr_of = |v: &Vec<T>, i| match v.get(i) {
@@ -1,10 +1,8 @@
-use crate::{
-    parse::{
-        instruction::{parse_usize, Instruction},
-        skip_whitespace,
-    },
-    Ship,
+use super::{
+    parse::instruction::{parse_usize, Instruction},
+    Day5, Ship,
 };
+use crate::{Error, Solution};
 
 #[rustfmt::skip]
 const CONTENT_NUMBER_EXAMPLE_STR: &str = r"
@@ -14,13 +12,6 @@ const CONTENT_NUMBER_EXAMPLE_STR: &str = r"
 [1] [2] [9]
  1   2   3 ";
 
-#[rustfmt::skip]
-const INST_CONTENT_EXAMPLE_STR: &str = r"
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2";
-
 #[test]
 fn display_empty_ship() {
     let ship: Ship<char> = Ship::new_empty_ship(0);
@@ -56,6 +47,13 @@ fn parse_ship() {
 
 /*
 
+#[rustfmt::skip]
+const INST_CONTENT_EXAMPLE_STR: &str = r"
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
 // Expect fail for test data
 #[test]
 fn execute() {
@@ -79,7 +77,7 @@ fn execute() {
 
 #[test]
 fn parse_instruction() {
-    const DATA: &'static str = "move 100 from 1 to 9";
+    const DATA: &str = "move 100 from 1 to 9";
     let (rest, instruction) = Instruction::parse(DATA).unwrap();
     assert!(rest.is_empty());
     assert_eq!(Instruction::new(100, 1, 9), instruction);
@@ -87,12 +85,74 @@ fn parse_instruction() {
 
 #[test]
 fn parse_number() {
-    const DATA: &'static str = "999junk";
+    const DATA: &str = "999junk";
     let (rest, number) = parse_usize(DATA).unwrap();
     assert_eq!(rest, "junk");
     assert_eq!(number, 999);
-    const DATA2: &'static str = "90563";
+    const DATA2: &str = "90563";
     let (rest, number) = parse_usize(DATA2).unwrap();
     assert_eq!(rest, "");
     assert_eq!(number, 90563);
 }
+
+#[rustfmt::skip]
+const AOC_EXAMPLE_STR: &str = r"
+    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+#[test]
+fn crate_mover_9000_moves_one_crate_at_a_time() {
+    assert_eq!(
+        Day5::part1(&Day5::parse(&AOC_EXAMPLE_STR[1..]).unwrap()),
+        Ok("CMZ".to_string())
+    );
+}
+
+#[test]
+fn crate_mover_9001_moves_crates_at_once() {
+    assert_eq!(
+        Day5::part2(&Day5::parse(&AOC_EXAMPLE_STR[1..]).unwrap()),
+        Ok("MCD".to_string())
+    );
+}
+
+#[test]
+fn malformed_instructions_are_located() {
+    let input = AOC_EXAMPLE_STR[1..].replace("move 2 from 2", "move 2 frm 2");
+    assert_eq!(
+        Day5::parse(&input).err(),
+        Some(Error::parse(8, 8, "expected \"from\" or expected \"to\""))
+    );
+}
+
+#[test]
+fn malformed_crates_are_located() {
+    let input = AOC_EXAMPLE_STR[1..].replace("[N]", "(N)");
+    assert_eq!(
+        Day5::parse(&input).err(),
+        Some(Error::parse(
+            2,
+            1,
+            "expected a crate `[x]`, a stack index ` n ` or an empty cell"
+        ))
+    );
+}
+
+#[test]
+fn lifting_too_many_crates_is_reported() {
+    let input = AOC_EXAMPLE_STR[1..].replace("move 3 from 1", "move 4 from 1");
+    let procedure = Day5::parse(&input).unwrap();
+    assert_eq!(
+        Day5::part1(&procedure),
+        Err(Error::unsolvable(
+            "Model9000 trying to lift 4 whereas there are only 3"
+        ))
+    );
+}
@@ -0,0 +1,208 @@
+use std::hint::black_box;
+
+use crate::{
+    foldby::{FoldByExt, WindowReducer},
+    solution::Kernel,
+    Error, Result, Solution,
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().as_bytes().to_vec())
+    }
+
+    /// Index right after the start-of-packet marker
+    fn part1(datastream: &Self::Input) -> Result<Self::Answer1> {
+        report_marker(datastream, 4)
+    }
+
+    /// Index right after the start-of-message marker
+    fn part2(datastream: &Self::Input) -> Result<Self::Answer2> {
+        report_marker(datastream, 14)
+    }
+
+    fn kernels() -> Vec<Kernel<Self::Input>> {
+        vec![
+            ("has_duplicate/14", |_, datastream| {
+                datastream
+                    .windows(14)
+                    .for_each(|window| _ = black_box(has_duplicate(window)))
+            }),
+            ("distinct_windows/14", |_, datastream| {
+                datastream
+                    .iter()
+                    .copied()
+                    .slide_windows(14, 1, Distinct::default())
+                    .for_each(|distinct| _ = black_box(distinct))
+            }),
+        ]
+    }
+}
+
+fn report_marker(input: &[u8], start_seq_size: usize) -> Result<usize> {
+    match find_message_index(input, start_seq_size) {
+        Some((start, end, seq)) => {
+            log::info!(
+                "Index of sequence : {:?}, Index of message : {:?}, Sequence: {:?}",
+                start,
+                end,
+                seq
+            );
+            Ok(end)
+        }
+        None => Err(Error::unsolvable(format!(
+            "no sequence of {} different characters",
+            start_seq_size
+        ))),
+    }
+}
+
+fn find_message_index(s: &[u8], start_seq_size: usize) -> Option<(usize, usize, &[u8])> {
+    let seq_idx = s
+        .iter()
+        .copied()
+        .slide_windows(start_seq_size, 1, Distinct::default())
+        .position(|distinct| distinct == start_seq_size)?;
+    let end = seq_idx + start_seq_size;
+    Some((seq_idx, end, &s[seq_idx..end]))
+}
+
+/// Number of different bytes in the window
+struct Distinct {
+    counts: [usize; 256],
+    distinct: usize,
+}
+
+impl Default for Distinct {
+    fn default() -> Self {
+        Self {
+            counts: [0; 256],
+            distinct: 0,
+        }
+    }
+}
+
+impl WindowReducer<u8> for Distinct {
+    type Output = usize;
+
+    fn add(&mut self, item: &u8) {
+        let count = &mut self.counts[*item as usize];
+        if *count == 0 {
+            self.distinct += 1;
+        }
+        *count += 1;
+    }
+
+    fn remove(&mut self, item: &u8) {
+        let count = &mut self.counts[*item as usize];
+        *count -= 1;
+        if *count == 0 {
+            self.distinct -= 1;
+        }
+    }
+
+    fn value(&self) -> usize {
+        self.distinct
+    }
+}
+
+fn cross_myself<T>(list: &[T]) -> Vec<(&T, &T)>
+where
+    T: std::fmt::Debug,
+{
+    let mut acc = 0;
+    for l in 1..=list.len() {
+        acc += l - 1;
+    }
+    let mut vec = Vec::with_capacity(acc);
+    for (i, x) in list.iter().enumerate() {
+        for y in list[..i].iter() {
+            vec.push((x, y));
+        }
+    }
+    vec
+}
+
+fn has_duplicate<T>(list: &[T]) -> bool
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    cross_myself(list).iter().any(|(x, y)| x == y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{cross_myself, find_message_index, has_duplicate};
+
+    #[test]
+    fn validate_has_duplicate_for_w4() {
+        const TEST: &str = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        let has_no_dup_4_window = |w: &[u8]| {
+            w[0] != w[1]
+                && w[1] != w[2]
+                && w[2] != w[3]
+                && w[3] != w[0]
+                && w[0] != w[2]
+                && w[1] != w[3]
+        };
+
+        TEST.as_bytes().windows(4).for_each(|window4| {
+            println!("{:?}", window4);
+            cross_myself(window4).iter().for_each(|(l, r)| {
+                println!("{}-{}", l, r);
+            });
+            assert_eq!(has_duplicate(window4), !has_no_dup_4_window(window4))
+        })
+    }
+
+    #[test]
+    fn part_1_aoc_4chars() {
+        const T1: (&str, usize) = ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5);
+        const T2: (&str, usize) = ("nppdvjthqldpwncqszvftbrmjlhg", 6);
+        const T3: (&str, usize) = ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10);
+        const T4: (&str, usize) = ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11);
+
+        assert_eq!(
+            find_message_index(T1.0.as_bytes(), 4).map(|v| v.1),
+            Some(T1.1)
+        );
+        assert_eq!(
+            find_message_index(T2.0.as_bytes(), 4).map(|v| v.1),
+            Some(T2.1)
+        );
+        assert_eq!(
+            find_message_index(T3.0.as_bytes(), 4).map(|v| v.1),
+            Some(T3.1)
+        );
+        assert_eq!(
+            find_message_index(T4.0.as_bytes(), 4).map(|v| v.1),
+            Some(T4.1)
+        );
+    }
+
+    #[test]
+    fn part_2_aoc_14chars() {
+        let tests: Vec<(&str, usize)> = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ];
+
+        for test in tests {
+            assert_eq!(
+                find_message_index(test.0.as_bytes(), 14).map(|v| v.1),
+                Some(test.1)
+            );
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt::Display;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The input doesn't follow the expected format; line and column start at 1
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well formed but doesn't lead to an answer
+    Unsolvable(String),
+    /// The input couldn't be read
+    Io(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Error::Unsolvable(message.into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::Unsolvable(message) => write!(f, "no answer: {}", message),
+            Error::Io(message) => write!(f, "read error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e.to_string())
+    }
+}
+
+/// A parsing failure not located yet: `at` is the part of the input on which parsing failed
+#[derive(Debug, PartialEq)]
+pub struct Failure<'s> {
+    pub at: &'s str,
+    pub message: String,
+}
+
+impl<'s> Failure<'s> {
+    pub fn new(at: &'s str, message: impl Into<String>) -> Self {
+        Self {
+            at,
+            message: message.into(),
+        }
+    }
+
+    /// Turn the failure into an error giving the line and column of `at` in `input`,
+    /// the whole text `at` was sliced from
+    pub fn locate(self, input: &str) -> Error {
+        let (line, column) = position(input, self.at);
+        Error::parse(line, column, self.message)
+    }
+}
+
+/// Line and column (1 based) where `at` starts in `input`.
+/// When `at` isn't a slice of `input`, it's considered as a suffix of it.
+fn position(input: &str, at: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let at_start = at.as_ptr() as usize;
+    let mut offset = if (start..=start + input.len()).contains(&at_start) {
+        at_start - start
+    } else {
+        input.len().saturating_sub(at.len())
+    };
+    // a foreign suffix may start in the middle of a character of `input`
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Error, Failure};
+
+    #[test]
+    fn failures_are_located_in_their_input() {
+        const INPUT: &str = "first line\nsecond line\nthird";
+        let at = &INPUT[INPUT.find("line\nthird").unwrap()..];
+        assert_eq!(
+            Failure::new(at, "oops").locate(INPUT),
+            Error::parse(2, 8, "oops")
+        );
+        assert_eq!(
+            Failure::new(INPUT, "oops").locate(INPUT),
+            Error::parse(1, 1, "oops")
+        );
+        assert_eq!(
+            Failure::new(&INPUT[INPUT.len()..], "oops").locate(INPUT),
+            Error::parse(3, 6, "oops")
+        );
+    }
+
+    #[test]
+    fn foreign_slices_are_taken_as_suffixes() {
+        assert_eq!(
+            Failure::new("rd", "oops").locate("first\nthird"),
+            Error::parse(2, 4, "oops")
+        );
+        assert_eq!(
+            Failure::new("x", "oops").locate("é"),
+            Error::parse(1, 1, "oops")
+        );
+        assert_eq!(
+            Failure::new("x", "oops").locate("aé"),
+            Error::parse(1, 2, "oops")
+        );
+    }
+
+    #[test]
+    fn display_errors() {
+        assert_eq!(
+            Error::parse(3, 4, "unknown shape").to_string(),
+            "line 3, column 4: unknown shape"
+        );
+        assert_eq!(
+            Error::unsolvable("no marker").to_string(),
+            "no answer: no marker"
+        );
+        assert_eq!(
+            Error::from(std::io::Error::other("disk on fire")).to_string(),
+            "read error: disk on fire"
+        );
+    }
+}
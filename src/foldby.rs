@@ -1,26 +1,19 @@
+use std::collections::VecDeque;
+
 /// This instance reduce an existing iterator, grouping concecutive elements by the provided value.
 /// The reducer is called `by` times on the successive elements and behaves like a fold.
 /// The previous computation is given back to the reducer.
-/// The reducer takes an accumulator and the current value: (acc: T, cur: I::Item) -> {}
-pub struct FoldBy<I, F>
-where
-    I: Iterator,
-    I::Item: Default,
-    F: Fn(&I::Item, I::Item) -> I::Item,
-{
+/// The reducer takes an accumulator and the current value: (acc: O, cur: I::Item) -> O
+/// Each group starts from a clone of `init`, so the output type doesn't have to be the item type.
+pub struct FoldBy<I, O, F> {
     iter: I,
-    init: I::Item,
+    init: O,
     by: usize,
     reducer: F,
 }
 
-impl<I, F> FoldBy<I, F>
-where
-    I: Iterator,
-    I::Item: Default,
-    F: Fn(&I::Item, I::Item) -> I::Item,
-{
-    fn new(iter: I, by: usize, init: I::Item, reducer: F) -> Self {
+impl<I, O, F> FoldBy<I, O, F> {
+    fn new(iter: I, by: usize, init: O, reducer: F) -> Self {
         Self {
             iter,
             init,
@@ -30,60 +23,265 @@ where
     }
 }
 
-impl<I, F> Iterator for FoldBy<I, F>
+impl<I, O, F> Iterator for FoldBy<I, O, F>
 where
     I: Iterator,
-    I::Item: Default + std::fmt::Debug,
-    F: Fn(&I::Item, I::Item) -> I::Item,
+    O: Clone,
+    F: Fn(O, I::Item) -> O,
 {
-    type Item = I::Item;
+    type Item = O;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut acc = None;
-        for _ in 0..self.by {
-            let next = self.iter.next();
-            if next.is_none() {
-                return acc;
+        let first = self.iter.next()?;
+        let mut acc = (self.reducer)(self.init.clone(), first);
+        for _ in 1..self.by {
+            match self.iter.next() {
+                Some(next) => acc = (self.reducer)(acc, next),
+                None => break,
             }
-            acc = next.map(|v| (self.reducer)(acc.as_ref().unwrap_or(&self.init), v));
         }
-        acc
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.div_ceil(self.by),
+            upper.map(|upper| upper.div_ceil(self.by)),
+        )
+    }
+}
+
+impl<I, O, F> ExactSizeIterator for FoldBy<I, O, F>
+where
+    I: ExactSizeIterator,
+    O: Clone,
+    F: Fn(O, I::Item) -> O,
+{
+}
+
+/// Groups are taken from the back with the same boundaries as from the front,
+/// so the incomplete group, if any, is the first one taken from the back.
+/// The elements of a group are still folded in their original order.
+impl<I, O, F> DoubleEndedIterator for FoldBy<I, O, F>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    O: Clone,
+    F: Fn(O, I::Item) -> O,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let remaining = self.iter.len();
+        if remaining == 0 {
+            return None;
+        }
+        let group_len = match remaining % self.by {
+            0 => self.by,
+            partial => partial,
+        };
+        let mut group: Vec<I::Item> = (0..group_len)
+            .filter_map(|_| self.iter.next_back())
+            .collect();
+        group.reverse();
+        Some(group.into_iter().fold(self.init.clone(), &self.reducer))
     }
 }
 
 pub trait FoldByExt: Iterator {
-    fn fold_by<F>(self, by: usize, init: Self::Item, reducer: F) -> FoldBy<Self, F>
+    /// Fold each group of `by` consecutive elements, starting from `init`.
+    /// The last group may be incomplete.
+    fn fold_by<O, F>(self, by: usize, init: O, reducer: F) -> FoldBy<Self, O, F>
     where
         Self: Sized,
-        Self::Item: Default + Clone,
-        F: Fn(&Self::Item, Self::Item) -> Self::Item,
+        O: Clone,
+        F: Fn(O, Self::Item) -> O,
     {
         assert_ne!(by, 0);
         FoldBy::new(self, by, init, reducer)
     }
+
+    /// Fold each window of `size` consecutive elements, starting from `init`.
+    /// A new window starts every `step` elements, windows overlap when `step < size`.
+    /// Only complete windows are produced.
+    fn fold_windows<O, F>(
+        self,
+        size: usize,
+        step: usize,
+        init: O,
+        reducer: F,
+    ) -> FoldWindows<Self, O, F>
+    where
+        Self: Sized,
+        O: Clone,
+        F: Fn(O, &Self::Item) -> O,
+    {
+        FoldWindows {
+            window: Window::new(self, size, step),
+            init,
+            reducer,
+        }
+    }
+
+    /// Like `fold_windows`, but the aggregate is updated as elements enter and leave the window
+    /// instead of being folded again over the whole window.
+    fn slide_windows<R>(self, size: usize, step: usize, reducer: R) -> SlideWindows<Self, R>
+    where
+        Self: Sized,
+        R: WindowReducer<Self::Item>,
+    {
+        SlideWindows {
+            window: Window::new(self, size, step),
+            reducer,
+        }
+    }
 }
 
 /// Implement my reduce for all t being an iterator
-impl<T> FoldByExt for T
+impl<T> FoldByExt for T where T: Iterator {}
+
+/// Whether an element enters or leaves a window
+enum Slide {
+    Enter,
+    Leave,
+}
+
+/// Elements of the current window, buffered so that arbitrary iterators can be windowed
+struct Window<I: Iterator> {
+    iter: I,
+    items: VecDeque<I::Item>,
+    size: usize,
+    step: usize,
+    started: bool,
+}
+
+impl<I: Iterator> Window<I> {
+    fn new(iter: I, size: usize, step: usize) -> Self {
+        assert_ne!(size, 0);
+        assert_ne!(step, 0);
+        Self {
+            iter,
+            items: VecDeque::with_capacity(size),
+            size,
+            step,
+            started: false,
+        }
+    }
+
+    /// Move to the next complete window, telling which elements leave and enter it.
+    /// Returns false when the source is exhausted before the window is complete.
+    fn advance(&mut self, mut slide: impl FnMut(&I::Item, Slide)) -> bool {
+        if self.started {
+            for _ in 0..self.step {
+                match self.items.pop_front() {
+                    Some(item) => slide(&item, Slide::Leave),
+                    // step bigger than the window: skip elements never windowed
+                    None if self.iter.next().is_some() => {}
+                    None => return false,
+                }
+            }
+        }
+        self.started = true;
+        while self.items.len() < self.size {
+            match self.iter.next() {
+                Some(item) => {
+                    slide(&item, Slide::Enter);
+                    self.items.push_back(item);
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Folds each window of an iterator, see `FoldByExt::fold_windows`
+pub struct FoldWindows<I: Iterator, O, F> {
+    window: Window<I>,
+    init: O,
+    reducer: F,
+}
+
+impl<I, O, F> Iterator for FoldWindows<I, O, F>
 where
-    T: Iterator,
-    T::Item: Default + Clone,
+    I: Iterator,
+    O: Clone,
+    F: Fn(O, &I::Item) -> O,
 {
-    fn fold_by<F>(self, by: usize, init: Self::Item, reducer: F) -> FoldBy<Self, F>
-    where
-        Self: Sized,
-        Self::Item: Default + Clone,
-        F: Fn(&Self::Item, Self::Item) -> Self::Item,
-    {
-        assert_ne!(by, 0);
-        FoldBy::new(self, by, init, reducer)
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.window.advance(|_, _| {}) {
+            return None;
+        }
+        Some(
+            self.window
+                .items
+                .iter()
+                .fold(self.init.clone(), |acc, cur| (self.reducer)(acc, cur)),
+        )
+    }
+}
+
+/// An aggregate over a sliding window, updated one element at a time
+pub trait WindowReducer<T> {
+    type Output;
+
+    /// `item` enters the window
+    fn add(&mut self, item: &T);
+    /// `item` leaves the window
+    fn remove(&mut self, item: &T);
+    /// Aggregate of the elements currently in the window
+    fn value(&self) -> Self::Output;
+}
+
+/// Incremental aggregates of each window of an iterator, see `FoldByExt::slide_windows`
+pub struct SlideWindows<I: Iterator, R> {
+    window: Window<I>,
+    reducer: R,
+}
+
+impl<I, R> Iterator for SlideWindows<I, R>
+where
+    I: Iterator,
+    R: WindowReducer<I::Item>,
+{
+    type Item = R::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reducer = &mut self.reducer;
+        if !self.window.advance(|item, slide| match slide {
+            Slide::Enter => reducer.add(item),
+            Slide::Leave => reducer.remove(item),
+        }) {
+            return None;
+        }
+        Some(self.reducer.value())
     }
 }
 
 #[cfg(test)]
 mod test {
 
-    use super::FoldByExt;
+    use super::{FoldByExt, WindowReducer};
+
+    /// Sum of the window, updated incrementally
+    #[derive(Default)]
+    struct Sum(u32);
+
+    impl WindowReducer<u32> for Sum {
+        type Output = u32;
+
+        fn add(&mut self, item: &u32) {
+            self.0 += item;
+        }
+
+        fn remove(&mut self, item: &u32) {
+            self.0 -= item;
+        }
+
+        fn value(&self) -> u32 {
+            self.0
+        }
+    }
 
     #[test]
     fn it_iterates_over_elements_by_one() {
@@ -108,19 +306,170 @@ mod test {
         let elmts: Vec<char> = "abcdefghijklmnopqrstuvwxyz"
             .chars()
             .fold_by(3, 'a', |acc, cur| {
-                ((*acc as u8 + cur as u8) % 26 + 65) as char
+                ((acc as u8 + cur as u8) % 26 + 65) as char
             })
             .collect();
         dbg!(&elmts);
         assert_eq!(elmts, vec!['B', 'K', 'T', 'C', 'L', 'U', 'D', 'M', 'P']);
     }
 
-    /*
-    // Modifier le parametre init pour en faire un type O indépendant
     #[test]
     fn it_works_with_differents_in_out_types() {
-        let elmts: Vec<u8> = "abcdefghijklmnopqrstuvwxyz".chars().fold_by(4, String::with_capacity(3), |acc, cur| acc.concat(cur)).collect();
-        assert_eq!(elmts, vec![0]);
+        let elmts: Vec<String> = "abcdefghijklmnopqrstuvwxyz"
+            .chars()
+            .fold_by(4, String::with_capacity(4), |mut acc, cur| {
+                acc.push(cur);
+                acc
+            })
+            .collect();
+        assert_eq!(
+            elmts,
+            vec!["abcd", "efgh", "ijkl", "mnop", "qrst", "uvwx", "yz"]
+        );
+    }
+
+    #[test]
+    fn it_folds_lines_into_bitsets() {
+        let elmts: Vec<u64> = ["ab", "bc", "b", "a"]
+            .into_iter()
+            .fold_by(3, u64::MAX, |acc, line| {
+                acc & line.bytes().fold(0, |bits, b| bits | 1 << (b - b'a'))
+            })
+            .collect();
+        assert_eq!(elmts, vec![0b10, 0b1]);
+    }
+
+    #[test]
+    fn it_folds_overlapping_windows() {
+        let sums: Vec<u32> = (1..=5_u32)
+            .fold_windows(3, 1, 0, |acc, cur| acc + cur)
+            .collect();
+        assert_eq!(sums, vec![6, 9, 12]);
+
+        let words: Vec<String> = "abcdefg"
+            .chars()
+            .fold_windows(2, 2, String::new(), |mut acc, cur| {
+                acc.push(*cur);
+                acc
+            })
+            .collect();
+        assert_eq!(words, vec!["ab", "cd", "ef"]);
+    }
+
+    #[test]
+    fn it_skips_elements_between_distant_windows() {
+        let sums: Vec<u32> = (1..=8_u32)
+            .fold_windows(2, 3, 0, |acc, cur| acc + cur)
+            .collect();
+        assert_eq!(sums, vec![3, 9, 15]);
+    }
+
+    #[test]
+    fn it_yields_nothing_when_the_window_is_never_complete() {
+        assert_eq!(
+            (1..=2_u32)
+                .fold_windows(3, 1, 0, |acc, cur| acc + cur)
+                .count(),
+            0
+        );
+        assert_eq!((1..=2_u32).slide_windows(3, 1, Sum::default()).count(), 0);
+    }
+
+    #[test]
+    fn sliding_windows_match_folded_windows() {
+        let values: Vec<u32> = (0..50).map(|v| v * 7 % 13).collect();
+        for size in 1..6 {
+            for step in 1..8 {
+                let folded: Vec<u32> = values
+                    .iter()
+                    .copied()
+                    .fold_windows(size, step, 0, |acc, cur| acc + cur)
+                    .collect();
+                let slid: Vec<u32> = values
+                    .iter()
+                    .copied()
+                    .slide_windows(size, step, Sum::default())
+                    .collect();
+                let naive: Vec<u32> = values
+                    .windows(size)
+                    .step_by(step)
+                    .map(|w| w.iter().sum())
+                    .collect();
+                assert_eq!(folded, naive, "size {} step {}", size, step);
+                assert_eq!(slid, naive, "size {} step {}", size, step);
+            }
+        }
+    }
+
+    /// Order sensitive reducer, so that misordered groups are noticed
+    fn mix(acc: u32, cur: u32) -> u32 {
+        acc.wrapping_mul(31).wrapping_add(cur)
+    }
+
+    /// Reference implementation: folds of the chunks of a slice
+    fn chunked(values: &[u32], by: usize) -> Vec<u32> {
+        values
+            .chunks(by)
+            .map(|chunk| chunk.iter().copied().fold(1, mix))
+            .collect()
+    }
+
+    #[test]
+    fn prop_forward_and_backward_match_chunks() {
+        for len in 0..40_u32 {
+            let values: Vec<u32> = (0..len).collect();
+            for by in 1..8 {
+                let expected = chunked(&values, by);
+                let folded = || values.iter().copied().fold_by(by, 1, mix);
+
+                assert_eq!(folded().len(), expected.len(), "len {} by {}", len, by);
+                assert_eq!(folded().collect::<Vec<_>>(), expected);
+                let mut backward: Vec<u32> = folded().rev().collect();
+                backward.reverse();
+                assert_eq!(backward, expected, "len {} by {}", len, by);
+            }
+        }
+    }
+
+    #[test]
+    fn prop_mixed_directions_match_chunks() {
+        // each bit of `pattern` picks the side the next group is taken from
+        for len in 0..24_u32 {
+            let values: Vec<u32> = (0..len).collect();
+            for by in 1..6 {
+                for pattern in 0..64_u32 {
+                    let mut expected = values
+                        .chunks(by)
+                        .map(|chunk| chunk.iter().copied().fold(1, mix));
+                    let mut folded = values.iter().copied().fold_by(by, 1, mix);
+                    for step in 0.. {
+                        assert_eq!(folded.size_hint(), expected.size_hint());
+                        let (actual, wanted) = if pattern >> (step % 32) & 1 == 0 {
+                            (folded.next(), expected.next())
+                        } else {
+                            (folded.next_back(), expected.next_back())
+                        };
+                        assert_eq!(
+                            actual, wanted,
+                            "len {} by {} pattern {:b}",
+                            len, by, pattern
+                        );
+                        if actual.is_none() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn size_hint_is_kept_for_inexact_sources() {
+        let filtered = (0..10_u32)
+            .filter(|v| v % 2 == 0)
+            .fold_by(3, 0, |acc, cur| acc + cur);
+        assert_eq!(filtered.size_hint(), (0, Some(4)));
+        let unbounded = (0_u32..).fold_by(3, 0, |acc, cur| acc + cur);
+        assert_eq!(unbounded.size_hint(), (usize::MAX.div_ceil(3), None));
     }
-    */
 }
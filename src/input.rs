@@ -0,0 +1,124 @@
+use std::{
+    fmt::Display,
+    fs::{read_to_string, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable giving the directory holding the `dayN.dat` files
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Where a puzzle input is read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `-` stands for stdin, anything else is a path.
+    /// A relative path that doesn't exist from the current directory is looked up in the data directory,
+    /// so `--input day5_example.dat` works from anywhere.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            return Source::Stdin;
+        }
+        let path = PathBuf::from(arg);
+        if path.is_relative() && !path.exists() {
+            let in_data_dir = data_dir().join(&path);
+            if in_data_dir.exists() {
+                return Source::File(in_data_dir);
+            }
+        }
+        Source::File(path)
+    }
+
+    /// The input of the given day in the data directory
+    pub fn default_for(day: u8) -> Self {
+        Source::File(data_dir().join(format!("day{}.dat", day)))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => read_to_string(path),
+        }
+    }
+
+    /// A reader over the input, for the solutions that don't need all of it in memory
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::File(path) => Box::new(BufReader::new(File::open(path)?)),
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// `$AOC_DATA_DIR` when set, otherwise the `data` directory of this repository
+pub fn data_dir() -> PathBuf {
+    match std::env::var_os(DATA_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => repository_data_dir(),
+    }
+}
+
+/// The `data` directory of this repository, whatever `$AOC_DATA_DIR` says
+pub fn repository_data_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("data")
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::BufRead;
+
+    use super::{data_dir, repository_data_dir, Source};
+
+    #[test]
+    fn dash_is_stdin() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+    }
+
+    #[test]
+    fn repository_data_holds_the_answers() {
+        assert!(repository_data_dir().join("answers.toml").is_file());
+    }
+
+    #[test]
+    fn default_input_is_in_data_dir() {
+        assert_eq!(
+            Source::default_for(5),
+            Source::File(data_dir().join("day5.dat"))
+        );
+    }
+
+    #[test]
+    fn bare_file_names_are_looked_up_in_data_dir() {
+        let source = Source::from_arg("day5_example.dat");
+        assert_eq!(source, Source::File(data_dir().join("day5_example.dat")));
+        assert!(source.read().unwrap().contains("move 1 from 2 to 1"));
+        let mut first = String::new();
+        source.open().unwrap().read_line(&mut first).unwrap();
+        assert_eq!(first, "    [D]    \n");
+    }
+
+    #[test]
+    fn unknown_files_are_kept_as_is() {
+        let source = Source::from_arg("nowhere/to/be/found.dat");
+        assert_eq!(source.to_string(), "nowhere/to/be/found.dat");
+        assert!(source.read().is_err());
+        assert!(source.open().is_err());
+    }
+}
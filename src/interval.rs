@@ -0,0 +1,357 @@
+//! Closed intervals of integers, such as the sections `2-4` (2, 3 and 4),
+//! and sets of them kept sorted and merged.
+
+use std::fmt::{Debug, Display};
+
+/// Integer type the bounds of an interval are made of
+pub trait Discrete: Copy + Ord + Debug + Display {
+    /// The next value, `None` on overflow
+    fn succ(self) -> Option<Self>;
+
+    /// The previous value, `None` on overflow
+    fn pred(self) -> Option<Self>;
+
+    /// The value as an `i128`, which holds every value of the implementing types
+    fn widen(self) -> i128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(impl Discrete for $t {
+            fn succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn widen(self) -> i128 {
+                self as i128
+            }
+        })*
+    };
+}
+
+impl_discrete!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Every value from `start` to `end`, both included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Discrete> Interval<T> {
+    /// `None` when `end` comes before `start`
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// The interval holding only `value`
+    pub fn single(value: T) -> Self {
+        Self {
+            start: value,
+            end: value,
+        }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// Number of values, never 0
+    pub fn len(&self) -> u128 {
+        (self.end.widen() - self.start.widen()) as u128 + 1
+    }
+
+    /// Always false, an interval holds at least one value
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every value of `other` is in `self`
+    pub fn includes(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether a value is in both intervals
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Whether one interval starts right after the other ends
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        self.end.succ() == Some(other.start) || other.end.succ() == Some(self.start)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The interval covering both, `None` when values lie between them
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        (self.overlaps(other) || self.is_adjacent(other)).then(|| Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// The values of `self` out of `other`: none, one or two intervals
+    pub fn difference(&self, other: &Self) -> IntervalSet<T> {
+        IntervalSet::from(*self).difference(&IntervalSet::from(*other))
+    }
+}
+
+/// Written as `start-end`
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Values covered by some intervals, stored as sorted intervals neither overlapping nor adjacent
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    /// The intervals of the set, lowest first
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values of the set
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(idx).is_some_and(|i| i.contains(value))
+    }
+
+    /// From the lowest to the highest value of the set
+    pub fn span(&self) -> Option<Interval<T>> {
+        let (first, last) = (self.intervals.first()?, self.intervals.last()?);
+        Interval::new(first.start, last.end)
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let idx = self.intervals.partition_point(|i| i.start < interval.start);
+        self.intervals.insert(idx, interval);
+        self.normalise();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            intervals.extend(x.intersection(y));
+            // the interval ending first can't meet anything else
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values of `self` out of `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        for interval in &self.intervals {
+            // first value of `interval` not handled yet
+            let mut from = Some(interval.start);
+            let overlapping = other.intervals.iter().filter(|o| o.overlaps(interval));
+            for removed in overlapping {
+                let Some(start) = from else { break };
+                if let Some(end) = removed.start.pred() {
+                    intervals.extend(Interval::new(start, end.min(interval.end)));
+                }
+                from = removed.end.succ();
+            }
+            if let Some(start) = from {
+                intervals.extend(Interval::new(start, interval.end));
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values between the lowest and the highest values of the set that are out of it
+    pub fn gaps(&self) -> Self {
+        let intervals = self
+            .intervals
+            .windows(2)
+            .filter_map(|w| Interval::new(w[0].end.succ()?, w[1].start.pred()?))
+            .collect();
+        Self { intervals }
+    }
+
+    /// Sort and merge the intervals
+    fn normalise(&mut self) {
+        self.intervals.sort_by_key(|i| i.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(self.intervals.len());
+        for interval in self.intervals.drain(..) {
+            match merged
+                .last_mut()
+                .and_then(|last| last.union(&interval).map(|u| (last, u)))
+            {
+                Some((last, union)) => *last = union,
+                None => merged.push(interval),
+            }
+        }
+        self.intervals = merged;
+    }
+}
+
+impl<T: Discrete> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Discrete> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl<T: Discrete> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self {
+            intervals: iter.into_iter().collect(),
+        };
+        set.normalise();
+        set
+    }
+}
+
+/// The intervals of the set, such as `{2-4, 6-8}`
+impl<T: Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let intervals: Vec<String> = self.intervals.iter().map(|i| i.to_string()).collect();
+        write!(f, "{{{}}}", intervals.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Interval, IntervalSet};
+
+    fn iv(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals.iter().map(|&(s, e)| iv(s, e)).collect()
+    }
+
+    #[test]
+    fn intervals_are_closed() {
+        assert_eq!(Interval::new(4, 2), None);
+        assert_eq!(iv(2, 4).len(), 3);
+        assert_eq!(Interval::single(7u8).len(), 1);
+        assert_eq!(Interval::new(i64::MIN, i64::MAX).unwrap().len(), 1 << 64);
+        assert!(iv(2, 4).contains(2) && iv(2, 4).contains(4) && !iv(2, 4).contains(5));
+        assert_eq!(iv(-3, 12).to_string(), "-3-12");
+    }
+
+    #[test]
+    fn containment_overlap_and_adjacency() {
+        assert!(iv(2, 8).includes(&iv(3, 7)));
+        assert!(iv(2, 8).includes(&iv(2, 8)));
+        assert!(!iv(3, 7).includes(&iv(2, 8)));
+        assert!(iv(5, 7).overlaps(&iv(7, 9)));
+        assert!(iv(7, 9).overlaps(&iv(5, 7)));
+        assert!(!iv(2, 3).overlaps(&iv(4, 5)));
+        assert!(iv(2, 3).is_adjacent(&iv(4, 5)));
+        assert!(iv(4, 5).is_adjacent(&iv(2, 3)));
+        assert!(!iv(2, 3).is_adjacent(&iv(5, 6)));
+        assert!(!Interval::single(u8::MAX).is_adjacent(&Interval::single(0)));
+    }
+
+    #[test]
+    fn interval_algebra() {
+        assert_eq!(iv(2, 6).intersection(&iv(4, 8)), Some(iv(4, 6)));
+        assert_eq!(iv(2, 3).intersection(&iv(4, 8)), None);
+        assert_eq!(iv(2, 6).union(&iv(4, 8)), Some(iv(2, 8)));
+        assert_eq!(iv(2, 3).union(&iv(4, 8)), Some(iv(2, 8)));
+        assert_eq!(iv(2, 3).union(&iv(5, 8)), None);
+        assert_eq!(iv(2, 8).difference(&iv(4, 5)), set(&[(2, 3), (6, 8)]));
+        assert_eq!(iv(2, 8).difference(&iv(1, 5)), set(&[(6, 8)]));
+        assert_eq!(iv(2, 8).difference(&iv(6, 9)), set(&[(2, 5)]));
+        assert_eq!(iv(2, 8).difference(&iv(0, 9)), set(&[]));
+        assert_eq!(iv(2, 8).difference(&iv(10, 12)), set(&[(2, 8)]));
+        let max = Interval::new(250u8, 255).unwrap();
+        assert_eq!(
+            max.difference(&Interval::single(255)),
+            Interval::new(250, 254).unwrap().into()
+        );
+    }
+
+    #[test]
+    fn sets_are_normalised() {
+        let sections = set(&[(6, 8), (2, 4), (3, 5), (10, 10), (9, 9), (20, 22)]);
+        assert_eq!(sections.intervals(), &[iv(2, 10), iv(20, 22)]);
+        assert_eq!(sections.len(), 12);
+        assert_eq!(sections.to_string(), "{2-10, 20-22}");
+        assert_eq!(sections.span(), Some(iv(2, 22)));
+        assert!(sections.contains(10) && sections.contains(20));
+        assert!(!sections.contains(15) && !sections.contains(1) && !sections.contains(23));
+
+        let mut inserted = IntervalSet::new();
+        [iv(20, 22), iv(2, 4), iv(5, 10)]
+            .into_iter()
+            .for_each(|i| inserted.insert(i));
+        assert_eq!(inserted, sections);
+        assert_eq!(IntervalSet::<i32>::default().span(), None);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(1, 5), (10, 15), (20, 30)]);
+        let b = set(&[(4, 12), (25, 26), (40, 41)]);
+        assert_eq!(a.union(&b), set(&[(1, 15), (20, 30), (40, 41)]));
+        assert_eq!(a.intersection(&b), set(&[(4, 5), (10, 12), (25, 26)]));
+        assert_eq!(
+            a.difference(&b),
+            set(&[(1, 3), (13, 15), (20, 24), (27, 30)])
+        );
+        assert_eq!(b.difference(&a), set(&[(6, 9), (40, 41)]));
+        assert_eq!(a.gaps(), set(&[(6, 9), (16, 19)]));
+        assert_eq!(a.difference(&a), IntervalSet::new());
+        assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+    }
+}
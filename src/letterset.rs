@@ -0,0 +1,580 @@
+//! Sets of items stored as bit flags. An item is identified by its position in an
+//! [`Alphabet`], which also gives its priority: the position plus one.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign},
+};
+
+use crate::{error::Failure, Result};
+
+/// Positions held by a set: 0 to 255
+const CAPACITY: usize = 256;
+const WORDS: usize = CAPACITY / 64;
+
+/// A set of positions from 0 to 255, bit `n` being raised when `n` is in the set
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LetterSet([u64; WORDS]);
+
+impl LetterSet {
+    pub const EMPTY: LetterSet = LetterSet([0; WORDS]);
+    pub const ALL: LetterSet = LetterSet([u64::MAX; WORDS]);
+
+    pub fn insert(&mut self, position: u8) {
+        self.0[position as usize / 64] |= 1 << (position % 64);
+    }
+
+    pub fn contains(self, position: u8) -> bool {
+        self.0[position as usize / 64] >> (position % 64) & 1 == 1
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] | other.0[i]))
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] & other.0[i]))
+    }
+
+    pub fn len(self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0.iter().all(|&w| w == 0)
+    }
+
+    /// The lowest position of the set
+    pub fn first(self) -> Option<u8> {
+        self.0
+            .iter()
+            .position(|&w| w != 0)
+            .map(|i| (i * 64) as u8 + self.0[i].trailing_zeros() as u8)
+    }
+
+    /// The position of the set when it holds exactly one
+    pub fn single(self) -> Option<u8> {
+        (self.len() == 1).then(|| self.first()).flatten()
+    }
+
+    /// Positions of the set, lowest first
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        self.0.into_iter().enumerate().flat_map(|(i, mut bits)| {
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let position = (i * 64) as u8 + bits.trailing_zeros() as u8;
+                bits &= bits - 1;
+                Some(position)
+            })
+        })
+    }
+}
+
+impl FromIterator<u8> for LetterSet {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let mut set = Self::EMPTY;
+        iter.into_iter().for_each(|position| set.insert(position));
+        set
+    }
+}
+
+impl BitAnd for LetterSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for LetterSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs)
+    }
+}
+
+impl BitOr for LetterSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for LetterSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs)
+    }
+}
+
+/// The positions of the set, such as `{0, 51}`. Use [`Alphabet::describe`] to get the symbols.
+impl Display for LetterSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let positions: Vec<String> = self.iter().map(|p| p.to_string()).collect();
+        write!(f, "{{{}}}", positions.join(", "))
+    }
+}
+
+/// Number of sets holding each position, most common first then lowest first
+pub fn frequency(sets: impl IntoIterator<Item = LetterSet>) -> Vec<(u8, usize)> {
+    let mut counts = [0; CAPACITY];
+    sets.into_iter()
+        .flat_map(LetterSet::iter)
+        .for_each(|position| counts[position as usize] += 1);
+    let mut frequency: Vec<(u8, usize)> = (0..=255)
+        .zip(counts)
+        .filter(|&(_, count)| count > 0)
+        .collect();
+    frequency.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    frequency
+}
+
+/// The symbols items are written with, up to 256 of them
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alphabet {
+    symbols: Vec<char>,
+    /// Positions of the ASCII symbols, the most common ones
+    ascii: [Option<u8>; 128],
+    others: HashMap<char, u8>,
+    /// Fast path for sets, when every symbol is ASCII
+    bytes: Option<ByteTable>,
+}
+
+impl Alphabet {
+    /// `a` to `z` then `A` to `Z`: priorities 1 to 52
+    pub fn letters() -> Self {
+        Self::from_symbols(('a'..='z').chain('A'..='Z'))
+    }
+
+    /// `0` to `9`: priorities 1 to 10
+    pub fn digits() -> Self {
+        Self::from_symbols('0'..='9')
+    }
+
+    /// Every symbol of `s` in order, whitespace excepted, the first one having priority 1
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut symbols: Vec<char> = vec![];
+        for (i, symbol) in s.char_indices().filter(|(_, c)| !c.is_whitespace()) {
+            let locate = |message: String| Failure::new(&s[i..], message).locate(s);
+            if symbols.contains(&symbol) {
+                return Err(locate(format!("symbol {:?} used twice", symbol)));
+            }
+            if symbols.len() == CAPACITY {
+                return Err(locate(format!(
+                    "an alphabet holds at most {} symbols",
+                    CAPACITY
+                )));
+            }
+            symbols.push(symbol);
+        }
+        if symbols.is_empty() {
+            return Err(Failure::new(s, "an alphabet needs at least one symbol").locate(s));
+        }
+        Ok(Self::from_symbols(symbols))
+    }
+
+    /// Symbols must be unique and at most 256
+    fn from_symbols(symbols: impl IntoIterator<Item = char>) -> Self {
+        let symbols: Vec<char> = symbols.into_iter().collect();
+        let mut ascii = [None; 128];
+        let mut others = HashMap::new();
+        for (position, &symbol) in symbols.iter().enumerate() {
+            match ascii.get_mut(symbol as usize) {
+                Some(slot) => *slot = Some(position as u8),
+                None => _ = others.insert(symbol, position as u8),
+            }
+        }
+        let bytes = others.is_empty().then(|| ByteTable::new(&ascii));
+        Self {
+            symbols,
+            ascii,
+            others,
+            bytes,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Position of `symbol`, `None` when it isn't part of the alphabet
+    pub fn position(&self, symbol: char) -> Option<u8> {
+        match self.ascii.get(symbol as usize) {
+            Some(&position) => position,
+            None => self.others.get(&symbol).copied(),
+        }
+    }
+
+    /// Priority of `symbol`: 1 for the first symbol of the alphabet
+    pub fn priority(&self, symbol: char) -> Option<u32> {
+        self.position(symbol).map(|p| p as u32 + 1)
+    }
+
+    pub fn symbol(&self, position: u8) -> Option<char> {
+        self.symbols.get(position as usize).copied()
+    }
+
+    /// The items of `s`, failing on the first symbol out of the alphabet
+    pub fn set<'s>(&self, s: &'s str) -> std::result::Result<LetterSet, Failure<'s>> {
+        match &self.bytes {
+            Some(table) => table.set(s),
+            None => self.set_chars(s),
+        }
+    }
+
+    /// Table to look the items of ASCII text up, `None` when a symbol isn't ASCII
+    pub fn byte_table(&self) -> Option<&ByteTable> {
+        self.bytes.as_ref()
+    }
+
+    /// Same as [`Alphabet::set`], looking every character up on its own
+    pub fn set_chars<'s>(&self, s: &'s str) -> std::result::Result<LetterSet, Failure<'s>> {
+        let mut set = LetterSet::EMPTY;
+        for (i, symbol) in s.char_indices() {
+            let position = self
+                .position(symbol)
+                .ok_or_else(|| Failure::new(&s[i..], format!("unknown item {:?}", symbol)))?;
+            set.insert(position);
+        }
+        Ok(set)
+    }
+
+    /// The symbols of a set, such as `{a, p, Z}`
+    pub fn describe(&self, set: LetterSet) -> String {
+        let symbols: Vec<String> = set
+            .iter()
+            .map(|p| {
+                self.symbol(p)
+                    .map_or_else(|| format!("#{}", p), String::from)
+            })
+            .collect();
+        format!("{{{}}}", symbols.join(", "))
+    }
+}
+
+/// The flags of every byte of ASCII text, so that sets are built without decoding characters
+/// nor branching on each of them. The lookups don't vectorise: OR-ing them in fixed-size
+/// chunks measured slower than this plain loop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ByteTable {
+    /// Set of each byte, empty for bytes out of the alphabet
+    masks: [u128; 256],
+}
+
+impl ByteTable {
+    fn new(ascii: &[Option<u8>; 128]) -> Self {
+        let mut masks = [0; 256];
+        for (byte, position) in ascii.iter().enumerate() {
+            if let Some(position) = position {
+                masks[byte] = 1 << position;
+            }
+        }
+        Self { masks }
+    }
+
+    /// The items of `s`, failing on the first byte out of the alphabet
+    pub fn set<'s>(&self, s: &'s str) -> std::result::Result<LetterSet, Failure<'s>> {
+        let mut items = 0;
+        let mut unknown = false;
+        for &byte in s.as_bytes() {
+            let mask = self.masks[byte as usize];
+            items |= mask;
+            unknown |= mask == 0;
+        }
+        if unknown {
+            // bytes of multibyte characters are all unknown, the first one starts the character
+            let i = s
+                .bytes()
+                .position(|b| self.masks[b as usize] == 0)
+                .unwrap_or(0);
+            let symbol = s[i..].chars().next().unwrap_or_default();
+            return Err(Failure::new(&s[i..], format!("unknown item {:?}", symbol)));
+        }
+        Ok(Self::to_set(items))
+    }
+
+    /// The items of every line of `text`, as `self.set` on each of `text.lines()` but in a
+    /// single pass over the bytes: splitting the text in lines first costs about as much as
+    /// looking their bytes up.
+    pub fn line_sets<'t, 's>(&'t self, text: &'s str) -> LineSets<'t, 's> {
+        LineSets {
+            table: self,
+            text,
+            start: 0,
+        }
+    }
+
+    /// The set of a line once all its bytes are looked up
+    fn finish<'s>(
+        &self,
+        line: &'s str,
+        items: u128,
+        unknown: bool,
+    ) -> std::result::Result<LetterSet, Failure<'s>> {
+        if unknown {
+            // either the `\r` of a `\r\n` line ending or an item out of the alphabet
+            return self.set(line.strip_suffix('\r').unwrap_or(line));
+        }
+        Ok(Self::to_set(items))
+    }
+
+    fn to_set(items: u128) -> LetterSet {
+        LetterSet([items as u64, (items >> 64) as u64, 0, 0])
+    }
+}
+
+/// Iterator over the sets of the lines of a text, built by `ByteTable::line_sets`
+#[derive(Debug, Clone)]
+pub struct LineSets<'t, 's> {
+    table: &'t ByteTable,
+    text: &'s str,
+    /// Offset of the next line
+    start: usize,
+}
+
+impl<'s> Iterator for LineSets<'_, 's> {
+    type Item = std::result::Result<LetterSet, Failure<'s>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self
+            .text
+            .as_bytes()
+            .get(self.start..)
+            .filter(|r| !r.is_empty())?;
+        let (mut items, mut unknown) = (0, false);
+        let mut end = rest.len();
+        for (i, &byte) in rest.iter().enumerate() {
+            if byte == b'\n' {
+                end = i;
+                break;
+            }
+            let mask = self.table.masks[byte as usize];
+            items |= mask;
+            unknown |= mask == 0;
+        }
+        let line = &self.text[self.start..self.start + end];
+        self.start += end + 1;
+        Some(self.table.finish(line, items, unknown))
+    }
+}
+
+/// The items of `s` in [`Alphabet::letters`], computed without any table nor branch so that
+/// the loop autovectorises: once `A` is subtracted, `A` to `Z` are shifts 0 to 25 and `a` to
+/// `z` shifts 32 to 57 of a single word, any other byte being caught by folding the case.
+/// Fails as [`Alphabet::set`] does on the first byte out of the alphabet. Only faster than
+/// [`ByteTable::set`] when built for AVX2, which has variable shifts.
+pub fn letter_set(s: &str) -> std::result::Result<LetterSet, Failure<'_>> {
+    const LETTERS: u64 = (1 << 26) - 1;
+    let (mut bits, mut unknown) = (0_u64, 0_u8);
+    for &byte in s.as_bytes() {
+        let shift = byte.wrapping_sub(b'A');
+        bits |= 1 << (shift & 63);
+        unknown |= ((shift & !0x20) >= 26) as u8;
+    }
+    if unknown != 0 {
+        return Alphabet::letters().set(s);
+    }
+    let (upper, lower) = (bits & LETTERS, bits >> 32);
+    Ok(LetterSet([lower | upper << 26, 0, 0, 0]))
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::letters()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{letter_set, Alphabet, LetterSet};
+    use crate::Error;
+
+    #[test]
+    fn letter_priorities_are_valid() {
+        let letters = Alphabet::letters();
+        assert_eq!(letters.len(), 52);
+        assert_eq!(letters.priority('a'), Some(1));
+        assert_eq!(letters.priority('z'), Some(26));
+        assert_eq!(letters.priority('A'), Some(27));
+        assert_eq!(letters.priority('Z'), Some(52));
+        assert_eq!(letters.priority('-'), None);
+        assert_eq!(letters.priority('é'), None);
+    }
+
+    #[test]
+    fn convert_positions_and_symbols() {
+        for alphabet in [Alphabet::letters(), Alphabet::digits()] {
+            for position in 0..alphabet.len() as u8 {
+                let symbol = alphabet.symbol(position).unwrap();
+                assert_eq!(alphabet.position(symbol), Some(position));
+            }
+            assert_eq!(alphabet.symbol(alphabet.len() as u8), None);
+        }
+        assert_eq!(Alphabet::digits().priority('0'), Some(1));
+    }
+
+    #[test]
+    fn custom_alphabets() {
+        let greek = Alphabet::parse("αβγ δ €").unwrap();
+        assert_eq!(greek.len(), 5);
+        assert_eq!(greek.priority('€'), Some(5));
+        assert_eq!(greek.describe(greek.set("€βα").unwrap()), "{α, β, €}");
+
+        let wide: String = (0..256)
+            .map(|i| char::from_u32(0x100 + i).unwrap())
+            .collect();
+        let wide = Alphabet::parse(&wide).unwrap();
+        assert_eq!(wide.position('\u{1ff}'), Some(255));
+        assert_eq!(wide.set("\u{1ff}\u{100}").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn invalid_alphabets_are_located() {
+        assert_eq!(
+            Alphabet::parse("abc\nda"),
+            Err(Error::parse(2, 2, "symbol 'a' used twice"))
+        );
+        assert_eq!(
+            Alphabet::parse(" \n"),
+            Err(Error::parse(1, 1, "an alphabet needs at least one symbol"))
+        );
+        let too_long: String = (0..257)
+            .map(|i| char::from_u32(0x100 + i).unwrap())
+            .collect();
+        assert_eq!(
+            Alphabet::parse(&too_long),
+            Err(Error::parse(
+                1,
+                257,
+                "an alphabet holds at most 256 symbols"
+            ))
+        );
+    }
+
+    #[test]
+    fn unknown_items_are_reported() {
+        let line = "abc-d";
+        let failure = Alphabet::letters().set(line).unwrap_err();
+        assert_eq!(failure.message, "unknown item '-'");
+        assert_eq!(failure.at, "-d");
+        assert!(Alphabet::digits().set("0123456789").is_ok());
+        assert!(Alphabet::digits().set("12a").is_err());
+    }
+
+    #[test]
+    fn byte_tables_match_characters() {
+        let digits = Alphabet::digits();
+        let table = digits.byte_table().unwrap();
+        for line in ["", "0", "9876543210", "1234567890123", "5a", "12é"] {
+            assert_eq!(table.set(line), digits.set_chars(line), "{}", line);
+        }
+        assert_eq!(table.set("12é").unwrap_err().at, "é");
+
+        let ascii: String = (0..128u8).map(char::from).collect();
+        let ascii = Alphabet::from_symbols(ascii.chars());
+        let all = ascii.byte_table().unwrap().set("\x7f\x00~A").unwrap();
+        assert_eq!(all.iter().collect::<Vec<u8>>(), vec![0, 65, 126, 127]);
+        assert!(Alphabet::parse("aé").unwrap().byte_table().is_none());
+    }
+
+    #[test]
+    fn letter_sets_match_the_letters() {
+        let letters = Alphabet::letters();
+        let ascii = (0..128u8).map(|b| char::from(b).to_string());
+        let lines = [
+            "",
+            "azAZ",
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "ab1b",
+            "@[`{",
+            "ab\r",
+            "12é",
+        ];
+        for line in ascii.chain(lines.map(String::from)) {
+            assert_eq!(letter_set(&line), letters.set(&line), "{:?}", line);
+        }
+        assert_eq!(letter_set("ab1b").unwrap_err().at, "1b");
+    }
+
+    #[test]
+    fn line_sets_match_lines() {
+        let letters = Alphabet::letters();
+        let table = letters.byte_table().unwrap();
+        for text in [
+            "",
+            "\n",
+            "abc",
+            "abc\nZ\n",
+            "ab\r\n\ncd",
+            "ab\nc1d\n",
+            "ab\nc\rd\n",
+        ] {
+            let sets = text
+                .lines()
+                .map(|line| letters.set(line))
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(
+                table.line_sets(text).collect::<Result<Vec<_>, _>>(),
+                sets,
+                "{:?}",
+                text
+            );
+        }
+        let mut sets = table.line_sets("ab\nc1d\n");
+        assert!(sets.next().unwrap().is_ok());
+        assert_eq!(sets.next().unwrap().unwrap_err().at, "1d");
+        assert!(sets.next().is_none());
+    }
+
+    #[test]
+    fn set_operations() {
+        let letters = Alphabet::letters();
+        let set = |s| letters.set(s).unwrap();
+        let abc = set("abcabc");
+        let bcd = set("bcd");
+        assert_eq!(abc.len(), 3);
+        assert_eq!(abc & bcd, set("bc"));
+        assert_eq!(abc | bcd, set("dcba"));
+        assert_eq!(abc.intersection(set("XYZ")), LetterSet::EMPTY);
+        assert!(abc.contains(2));
+        assert!(!abc.contains(3));
+        assert!(!abc.contains(200));
+        assert_eq!((LetterSet::ALL & abc), abc);
+    }
+
+    #[test]
+    fn single_elements_are_found() {
+        let set = |positions: &[u8]| positions.iter().copied().collect::<LetterSet>();
+        assert_eq!(set(&[0]).single(), Some(0));
+        assert_eq!(set(&[2]).single(), Some(2));
+        assert_eq!(set(&[200]).single(), Some(200));
+        assert_eq!(set(&[255]).single(), Some(255));
+        assert_eq!(set(&[1, 2]).single(), None);
+        assert_eq!(set(&[130, 70]).first(), Some(70));
+        assert_eq!(LetterSet::EMPTY.first(), None);
+        assert_eq!(LetterSet::ALL.len(), 256);
+    }
+
+    #[test]
+    fn iteration_and_display() {
+        let letters = Alphabet::letters();
+        let set = letters.set("Zpa").unwrap();
+        assert_eq!(set.iter().collect::<Vec<u8>>(), vec![0, 15, 51]);
+        assert_eq!(set.iter().collect::<LetterSet>(), set);
+        assert_eq!(set.to_string(), "{0, 15, 51}");
+        assert_eq!(letters.describe(set), "{a, p, Z}");
+        assert_eq!(
+            letters.describe([60, 255].into_iter().collect()),
+            "{#60, #255}"
+        );
+        assert_eq!(LetterSet::EMPTY.to_string(), "{}");
+        let high: LetterSet = [64, 255, 63].into_iter().collect();
+        assert_eq!(high.iter().collect::<Vec<u8>>(), vec![63, 64, 255]);
+    }
+}
@@ -1 +1,22 @@
+pub mod answers;
+pub mod bench;
+pub mod error;
 pub mod foldby;
+pub mod input;
+pub mod interval;
+pub mod letterset;
+pub mod parse;
+pub mod registry;
+pub mod report;
+pub mod rng;
+pub mod solution;
+
+pub use error::{Error, Result};
+pub use solution::Solution;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
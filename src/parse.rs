@@ -0,0 +1,332 @@
+//! Small parser combinators.
+//!
+//! A parser takes the input and returns what remains of it along with the parsed value.
+//! On failure, it returns a [`Failure`] pointing to where the input couldn't be parsed,
+//! which [`Failure::locate`] turns into an error with a line and a column.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::Failure;
+
+/// Remaining input and parsed value, or where parsing failed
+pub type PResult<'s, T> = Result<(&'s str, T), Failure<'s>>;
+
+/// Match exactly `expected`
+pub fn tag<'s>(expected: &'static str) -> impl Fn(&'s str) -> PResult<'s, &'s str> {
+    move |input: &'s str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((rest, &input[..expected.len()])),
+        None => Err(Failure::new(input, format!("expected {:?}", expected))),
+    }
+}
+
+/// Match one char validating `predicate`, `what` describes the expected char in errors
+pub fn satisfy<'s>(
+    what: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Fn(&'s str) -> PResult<'s, char> {
+    move |input: &'s str| {
+        let mut chars = input.chars();
+        match chars.next() {
+            Some(c) if predicate(c) => Ok((chars.as_str(), c)),
+            _ => Err(Failure::new(input, format!("expected {}", what))),
+        }
+    }
+}
+
+/// Any char
+pub fn any_char(input: &str) -> PResult<'_, char> {
+    satisfy("a character", |_| true)(input)
+}
+
+/// A decimal number, with an optional leading `-`
+pub fn number<'s, N>() -> impl Fn(&'s str) -> PResult<'s, N>
+where
+    N: FromStr,
+    N::Err: Display,
+{
+    move |input: &'s str| {
+        let sign = usize::from(input.starts_with('-'));
+        let digits = input[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return Err(Failure::new(input, "expected a number"));
+        }
+        let (number, rest) = input.split_at(sign + digits);
+        number
+            .parse::<N>()
+            .map(|n| (rest, n))
+            .map_err(|e| Failure::new(input, format!("invalid number {:?}: {}", number, e)))
+    }
+}
+
+/// One or more chars that aren't whitespaces
+pub fn word(input: &str) -> PResult<'_, &str> {
+    let len = input.find(char::is_whitespace).unwrap_or(input.len());
+    if len == 0 {
+        return Err(Failure::new(input, "expected a word"));
+    }
+    Ok((&input[len..], &input[..len]))
+}
+
+/// Zero or more whitespaces, new lines included
+pub fn whitespace(input: &str) -> PResult<'_, &str> {
+    let rest = input.trim_start();
+    Ok((rest, &input[..input.len() - rest.len()]))
+}
+
+/// Zero or more spaces
+pub fn spaces(input: &str) -> PResult<'_, &str> {
+    let rest = input.trim_start_matches(' ');
+    Ok((rest, &input[..input.len() - rest.len()]))
+}
+
+/// The end of a line (or of the input)
+pub fn line_ending(input: &str) -> PResult<'_, &str> {
+    if input.is_empty() {
+        return Ok((input, input));
+    }
+    alt(tag("\n"), tag("\r\n"))(input)
+        .map_err(|_| Failure::new(input, "expected the end of the line"))
+}
+
+/// Transform the parsed value
+pub fn map<'s, A, B>(
+    parser: impl Fn(&'s str) -> PResult<'s, A>,
+    f: impl Fn(A) -> B,
+) -> impl Fn(&'s str) -> PResult<'s, B> {
+    move |input: &'s str| parser(input).map(|(rest, a)| (rest, f(a)))
+}
+
+/// Transform the parsed value with a conversion that may fail,
+/// the failure then points at the start of the parsed value
+pub fn try_map<'s, A, B, E: Display>(
+    parser: impl Fn(&'s str) -> PResult<'s, A>,
+    f: impl Fn(A) -> Result<B, E>,
+) -> impl Fn(&'s str) -> PResult<'s, B> {
+    move |input: &'s str| {
+        let (rest, a) = parser(input)?;
+        f(a).map(|b| (rest, b))
+            .map_err(|e| Failure::new(input, e.to_string()))
+    }
+}
+
+/// Both parsers, one after the other
+pub fn pair<'s, A, B>(
+    first: impl Fn(&'s str) -> PResult<'s, A>,
+    second: impl Fn(&'s str) -> PResult<'s, B>,
+) -> impl Fn(&'s str) -> PResult<'s, (A, B)> {
+    move |input: &'s str| {
+        let (rest, a) = first(input)?;
+        let (rest, b) = second(rest)?;
+        Ok((rest, (a, b)))
+    }
+}
+
+/// Both parsers, keeping the value of the second one
+pub fn preceded<'s, A, B>(
+    first: impl Fn(&'s str) -> PResult<'s, A>,
+    second: impl Fn(&'s str) -> PResult<'s, B>,
+) -> impl Fn(&'s str) -> PResult<'s, B> {
+    map(pair(first, second), |(_, b)| b)
+}
+
+/// Both parsers, keeping the value of the first one
+pub fn terminated<'s, A, B>(
+    first: impl Fn(&'s str) -> PResult<'s, A>,
+    second: impl Fn(&'s str) -> PResult<'s, B>,
+) -> impl Fn(&'s str) -> PResult<'s, A> {
+    map(pair(first, second), |(a, _)| a)
+}
+
+/// The parser or nothing
+pub fn opt<'s, T>(
+    parser: impl Fn(&'s str) -> PResult<'s, T>,
+) -> impl Fn(&'s str) -> PResult<'s, Option<T>> {
+    move |input: &'s str| match parser(input) {
+        Ok((rest, value)) => Ok((rest, Some(value))),
+        Err(_) => Ok((input, None)),
+    }
+}
+
+/// The first parser, or the second one when the first fails.
+/// When both fail, the failure that went the furthest in the input is kept.
+pub fn alt<'s, T>(
+    first: impl Fn(&'s str) -> PResult<'s, T>,
+    second: impl Fn(&'s str) -> PResult<'s, T>,
+) -> impl Fn(&'s str) -> PResult<'s, T> {
+    move |input: &'s str| {
+        first(input).or_else(|f1| {
+            second(input).map_err(|f2| match f1.at.len().cmp(&f2.at.len()) {
+                std::cmp::Ordering::Less => f1,
+                std::cmp::Ordering::Greater => f2,
+                std::cmp::Ordering::Equal => {
+                    Failure::new(f1.at, format!("{} or {}", f1.message, f2.message))
+                }
+            })
+        })
+    }
+}
+
+/// The parser, as many times as possible (possibly zero)
+pub fn many<'s, T>(
+    parser: impl Fn(&'s str) -> PResult<'s, T>,
+) -> impl Fn(&'s str) -> PResult<'s, Vec<T>> {
+    move |mut input: &'s str| {
+        let mut values = vec![];
+        while let Ok((rest, value)) = parser(input) {
+            if rest.len() == input.len() {
+                // no progress, stop before looping forever
+                break;
+            }
+            values.push(value);
+            input = rest;
+        }
+        Ok((input, values))
+    }
+}
+
+/// At least one item, items being separated by `separator`.
+/// A separator followed by something that isn't an item at all is left in the input,
+/// but an item failing after having been partly parsed is returned as is,
+/// so that the failure points inside the faulty item.
+pub fn separated_list<'s, T, S>(
+    item: impl Fn(&'s str) -> PResult<'s, T>,
+    separator: impl Fn(&'s str) -> PResult<'s, S>,
+) -> impl Fn(&'s str) -> PResult<'s, Vec<T>> {
+    move |input: &'s str| {
+        let (mut input, first) = item(input)?;
+        let mut values = vec![first];
+        while let Ok((after_separator, _)) = separator(input) {
+            match item(after_separator) {
+                Ok((rest, value)) => {
+                    values.push(value);
+                    input = rest;
+                }
+                Err(failure) if failure.at.len() == after_separator.len() => break,
+                Err(failure) => return Err(failure),
+            }
+        }
+        Ok((input, values))
+    }
+}
+
+/// Run the parser and require that it consumes the whole input
+pub fn parse_all<'s, T>(
+    parser: impl Fn(&'s str) -> PResult<'s, T>,
+    input: &'s str,
+) -> Result<T, Failure<'s>> {
+    match parser(input)? {
+        ("", value) => Ok(value),
+        (rest, _) => Err(Failure::new(rest, "unexpected trailing characters")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        alt, any_char, line_ending, many, map, number, opt, pair, parse_all, preceded,
+        separated_list, tag, terminated, try_map, whitespace, word,
+    };
+    use crate::{error::Failure, Error};
+
+    #[test]
+    fn tag_matches_prefix() {
+        assert_eq!(tag("move")("move 1"), Ok((" 1", "move")));
+        assert_eq!(
+            tag("move")("mve 1"),
+            Err(Failure::new("mve 1", "expected \"move\""))
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(number::<u32>()("999junk"), Ok(("junk", 999)));
+        assert_eq!(number::<i64>()("-12,"), Ok((",", -12)));
+        assert_eq!(
+            number::<u8>()("x"),
+            Err(Failure::new("x", "expected a number"))
+        );
+        assert_eq!(
+            number::<u8>()("256"),
+            Err(Failure::new(
+                "256",
+                "invalid number \"256\": number too large to fit in target type"
+            ))
+        );
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(word("A Y"), Ok((" Y", "A")));
+        assert_eq!(word("Y"), Ok(("", "Y")));
+        assert_eq!(word(" Y"), Err(Failure::new(" Y", "expected a word")));
+        assert_eq!(word(""), Err(Failure::new("", "expected a word")));
+    }
+
+    #[test]
+    fn sequences() {
+        let range = pair(terminated(number::<u32>(), tag("-")), number::<u32>());
+        assert_eq!(range("2-4,6-8"), Ok((",6-8", (2, 4))));
+        assert_eq!(preceded(tag("["), any_char)("[Z]"), Ok(("]", 'Z')));
+        assert_eq!(opt(tag("x"))("y"), Ok(("y", None)));
+    }
+
+    #[test]
+    fn alternatives_keep_the_furthest_failure() {
+        let dir = alt(map(tag("from"), |_| 0), map(tag("to"), |_| 1));
+        assert_eq!(dir("to 3"), Ok((" 3", 1)));
+        assert_eq!(
+            dir("at").unwrap_err().message,
+            "expected \"from\" or expected \"to\""
+        );
+
+        let input = "move 1";
+        let deep = alt(preceded(tag("move "), tag("x")), tag("stay"));
+        assert_eq!(
+            deep(input),
+            Err(Failure::new(&input[5..], "expected \"x\""))
+        );
+    }
+
+    #[test]
+    fn repetitions() {
+        assert_eq!(many(tag("ab"))("ababa"), Ok(("a", vec!["ab", "ab"])));
+        assert_eq!(many(whitespace)("a"), Ok(("a", vec![])));
+        assert_eq!(
+            separated_list(number::<u8>(), tag(","))("1,2,3;4"),
+            Ok((";4", vec![1, 2, 3]))
+        );
+        assert_eq!(
+            separated_list(number::<u8>(), tag(","))("1,2,x"),
+            Ok((",x", vec![1, 2]))
+        );
+        assert_eq!(
+            separated_list(pair(number::<u8>(), tag("!")), tag(","))("1!,2?"),
+            Err(Failure::new("?", "expected \"!\""))
+        );
+        assert!(separated_list(number::<u8>(), tag(","))("x").is_err());
+    }
+
+    #[test]
+    fn conversions_failures_point_to_the_value() {
+        let even = try_map(number::<u8>(), |n| {
+            if n % 2 == 0 {
+                Ok(n)
+            } else {
+                Err(format!("{} is odd", n))
+            }
+        });
+        assert_eq!(even("4"), Ok(("", 4)));
+        assert_eq!(even("3"), Err(Failure::new("3", "3 is odd")));
+    }
+
+    #[test]
+    fn parse_all_requires_the_whole_input() {
+        const INPUT: &str = "1,2\n3;4\n";
+        let lines = separated_list(separated_list(number::<u8>(), tag(",")), line_ending);
+        let failure = parse_all(terminated(lines, whitespace), INPUT).unwrap_err();
+        assert_eq!(
+            failure.locate(INPUT),
+            Error::parse(2, 2, "unexpected trailing characters")
+        );
+    }
+}
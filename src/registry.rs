@@ -0,0 +1,305 @@
+use std::io::BufRead;
+
+use crate::{
+    bench::{self, Stats},
+    day1::{self, Day1},
+    day2::{self, Day2},
+    day3::{self, Day3},
+    day4::Day4,
+    day5::Day5,
+    day6::Day6,
+    report::{Format, Style},
+    rng::Rng,
+    Result, Solution,
+};
+
+/// A solver takes the whole puzzle input and returns the answer to display
+pub type Solver = fn(&str) -> Result<String>;
+
+/// A solver reading the puzzle input by itself, without keeping all of it when it can
+pub type ReadSolver = fn(&mut dyn BufRead) -> Result<String>;
+
+fn solve_part1<S: Solution>(input: &str) -> Result<String> {
+    Ok(S::part1(&S::parse(input)?)?.to_string())
+}
+
+fn solve_part2<S: Solution>(input: &str) -> Result<String> {
+    Ok(S::part2(&S::parse(input)?)?.to_string())
+}
+
+fn read_part1<S: Solution>(reader: &mut dyn BufRead) -> Result<String> {
+    Ok(S::part1(&S::read(reader)?)?.to_string())
+}
+
+fn read_part2<S: Solution>(reader: &mut dyn BufRead) -> Result<String> {
+    Ok(S::part2(&S::read(reader)?)?.to_string())
+}
+
+fn read_both<S: Solution>(reader: &mut dyn BufRead) -> Result<Vec<Result<String>>> {
+    let parsed = S::read(reader)?;
+    Ok(vec![
+        S::part1(&parsed).map(|answer| answer.to_string()),
+        S::part2(&parsed).map(|answer| answer.to_string()),
+    ])
+}
+
+/// The whole text of `reader`, for the reports that don't stream their input
+fn read_all(reader: &mut dyn BufRead) -> Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// One part of one day, as known by the `aoc` runner
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub solve: Solver,
+    pub read: ReadSolver,
+}
+
+const fn part1<S: Solution>() -> Entry {
+    Entry {
+        day: S::DAY,
+        part: 1,
+        solve: solve_part1::<S>,
+        read: read_part1::<S>,
+    }
+}
+
+const fn part2<S: Solution>() -> Entry {
+    Entry {
+        day: S::DAY,
+        part: 2,
+        solve: solve_part2::<S>,
+        read: read_part2::<S>,
+    }
+}
+
+/// Every solver, ordered by day then by part
+pub const SOLVERS: &[Entry] = &[
+    part1::<Day1>(),
+    part2::<Day1>(),
+    part1::<Day2>(),
+    part2::<Day2>(),
+    part1::<Day3>(),
+    part2::<Day3>(),
+    part1::<Day4>(),
+    part2::<Day4>(),
+    part1::<Day5>(),
+    part2::<Day5>(),
+    part1::<Day6>(),
+    part2::<Day6>(),
+];
+
+/// Look for the solver of the given day and part
+pub fn find(day: u8, part: u8) -> Option<&'static Entry> {
+    SOLVERS.iter().find(|e| e.day == day && e.part == part)
+}
+
+/// Solvers of every part of the given day, in order
+pub fn parts(day: u8) -> impl Iterator<Item = &'static Entry> {
+    SOLVERS.iter().filter(move |e| e.day == day)
+}
+
+/// Solves every part of a day from a single parse of the puzzle input
+pub type Solve = fn(&mut dyn BufRead) -> Result<Vec<Result<String>>>;
+
+/// Benchmarks of one day: takes the input and the number of iterations
+pub type Bench = fn(&str, usize) -> Result<Vec<Stats>>;
+
+/// Report about a puzzle input read from a reader, rendered in the given format
+pub type Reporter = fn(&mut dyn BufRead, Format) -> Result<String>;
+
+/// Drawing of a puzzle input in the given style
+pub type Visualiser = fn(&str, Style) -> Result<String>;
+
+/// Random puzzle input of about the given number of bytes
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// What the runner can do with a whole day
+pub struct Day {
+    pub day: u8,
+    pub solve: Solve,
+    pub bench: Bench,
+    pub report: Option<Reporter>,
+    pub generate: Option<Generator>,
+    pub visualise: Option<Visualiser>,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        day: S::DAY,
+        solve: read_both::<S>,
+        bench: bench::bench::<S>,
+        report: None,
+        generate: None,
+        visualise: None,
+    }
+}
+
+impl Day {
+    const fn with_report(self, report: Reporter) -> Self {
+        Self {
+            report: Some(report),
+            ..self
+        }
+    }
+
+    const fn with_visualiser(self, visualise: Visualiser) -> Self {
+        Self {
+            visualise: Some(visualise),
+            ..self
+        }
+    }
+
+    const fn with_generator(self, generate: Generator) -> Self {
+        Self {
+            generate: Some(generate),
+            ..self
+        }
+    }
+}
+
+/// Every day, in order
+pub const DAYS: &[Day] = &[
+    day::<Day1>().with_report(|reader, format| day1::read_report(reader, format)),
+    day::<Day2>().with_report(|reader, format| day2::report(&read_all(reader)?, format)),
+    day::<Day3>()
+        .with_report(|reader, format| day3::report(&read_all(reader)?, format))
+        .with_generator(day3::generate)
+        .with_visualiser(day3::visualise),
+    day::<Day4>(),
+    day::<Day5>(),
+    day::<Day6>(),
+];
+
+/// Look for a day
+pub fn find_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find, find_day, parts, DAYS, SOLVERS};
+    use std::io::{BufReader, Read};
+
+    use crate::input::Source;
+    use crate::report::Format;
+    use crate::rng::Rng;
+    use crate::Error;
+
+    #[test]
+    fn every_day_has_two_parts() {
+        for day in 1..=6 {
+            assert!(find(day, 1).is_some(), "day {} part 1 missing", day);
+            assert!(find(day, 2).is_some(), "day {} part 2 missing", day);
+        }
+        assert_eq!(SOLVERS.len(), 12);
+    }
+
+    #[test]
+    fn unknown_solvers_are_not_found() {
+        assert!(find(0, 1).is_none());
+        assert!(find(1, 3).is_none());
+        assert!(find(25, 1).is_none());
+        assert!(find_day(25).is_none());
+        assert_eq!(parts(25).count(), 0);
+    }
+
+    /// Every registered part must answer on its puzzle input, nothing is selected at build time
+    #[test]
+    fn every_part_answers() {
+        for day in 1..=6 {
+            let input = Source::default_for(day).read().unwrap();
+            let answers: Vec<String> = parts(day)
+                .map(|entry| (entry.solve)(&input).unwrap())
+                .collect();
+            assert_eq!(answers.len(), 2, "day {}", day);
+        }
+    }
+
+    #[test]
+    fn both_parts_share_one_parse() {
+        let day = find_day(3).unwrap();
+        let input = Source::default_for(3).read().unwrap();
+        let answers: Vec<String> = parts(3).map(|e| (e.solve)(&input).unwrap()).collect();
+        let both: Vec<String> = (day.solve)(&mut input.as_bytes())
+            .unwrap()
+            .into_iter()
+            .map(|answer| answer.unwrap())
+            .collect();
+        assert_eq!(both, answers);
+        assert_eq!(
+            (day.solve)(&mut "abc\n".as_bytes()),
+            Err(Error::parse(
+                1,
+                1,
+                "3 items can't be split in 2 compartments of the same size"
+            ))
+        );
+    }
+
+    /// `pattern` over and over, read without ever being stored as a whole
+    struct Cycle {
+        pattern: &'static [u8],
+        at: usize,
+    }
+
+    impl Read for Cycle {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            for byte in buf.iter_mut() {
+                *byte = self.pattern[self.at % self.pattern.len()];
+                self.at += 1;
+            }
+            Ok(buf.len())
+        }
+    }
+
+    #[test]
+    fn day1_streams_its_input() {
+        const ELVES: u64 = 500_000;
+        let inventory = || {
+            let cycle = Cycle {
+                pattern: b"100\n200\n\n",
+                at: 0,
+            };
+            BufReader::new(cycle.take(9 * ELVES))
+        };
+        let day = find_day(1).unwrap();
+        let answers: Vec<String> = (day.solve)(&mut inventory())
+            .unwrap()
+            .into_iter()
+            .map(|answer| answer.unwrap())
+            .collect();
+        assert_eq!(answers, ["300", "900"]);
+        assert_eq!(
+            (find(1, 2).unwrap().read)(&mut inventory()),
+            Ok("900".to_string())
+        );
+        let report = (day.report.unwrap())(&mut inventory(), Format::Json).unwrap();
+        assert!(
+            report.starts_with(&format!("{{\"elves\":{},", ELVES)),
+            "{}",
+            report
+        );
+    }
+
+    #[test]
+    fn days_with_a_report() {
+        assert!(find_day(1).unwrap().report.is_some());
+        assert!(find_day(2).unwrap().report.is_some());
+        assert!(find_day(3).unwrap().report.is_some());
+        assert!(find_day(3).unwrap().visualise.is_some());
+    }
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for day in DAYS.iter().filter(|d| d.generate.is_some()) {
+            let input = (day.generate.unwrap())(&mut Rng::new(1), 1000);
+            for entry in parts(day.day) {
+                assert!((entry.solve)(&input).is_ok(), "day {}", day.day);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+/// How a report is rendered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(format!("unknown format {}", other)),
+        }
+    }
+}
+
+/// How a visualisation is drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Style {
+    /// Text only, highlights are drawn with markers
+    Plain,
+    /// Highlights are drawn with ANSI colours, for terminals
+    Ansi,
+}
+
+impl Style {
+    /// `s` in bold red with ANSI colours, unchanged otherwise
+    pub fn highlight(self, s: &str) -> String {
+        match self {
+            Style::Plain => s.to_string(),
+            Style::Ansi => format!("\x1b[1;31m{}\x1b[0m", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Format, Style};
+
+    #[test]
+    fn formats_are_parsed() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn only_ansi_highlights() {
+        assert_eq!(Style::Plain.highlight("p"), "p");
+        assert_eq!(Style::Ansi.highlight("p"), "\x1b[1;31mp\x1b[0m");
+    }
+}
@@ -0,0 +1,58 @@
+//! Small deterministic pseudo random generator, good enough for simulations and generated inputs
+
+/// SplitMix64: one 64 bits state, every seed gives a different sequence
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, `bound` must not be 0
+    pub fn below(&mut self, bound: u64) -> u64 {
+        // rejection of the incomplete last range keeps the draw uniform
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let n = self.next_u64();
+            if n < zone {
+                return n % bound;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Rng;
+
+    #[test]
+    fn sequences_depend_on_the_seed() {
+        let mut a = Rng::new(1);
+        let mut b = Rng::new(1);
+        let mut c = Rng::new(2);
+        let a: Vec<u64> = (0..4).map(|_| a.next_u64()).collect();
+        assert_eq!(a, (0..4).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(a, (0..4).map(|_| c.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn draws_are_bounded_and_spread() {
+        let mut rng = Rng::new(2022);
+        let mut counts = [0; 5];
+        (0..5000).for_each(|_| counts[rng.below(5) as usize] += 1);
+        assert!(
+            counts.iter().all(|&c| (900..1100).contains(&c)),
+            "{:?}",
+            counts
+        );
+    }
+}
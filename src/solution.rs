@@ -0,0 +1,36 @@
+use std::{fmt::Display, io::BufRead};
+
+use crate::error::Result;
+
+/// A hot path of a solution, timed on its own by `aoc bench`. It is given the raw puzzle
+/// input and its parsed form.
+pub type Kernel<I> = (&'static str, fn(&str, &I));
+
+/// A day of the calendar: the puzzle input is parsed once and then given to both parts
+pub trait Solution {
+    /// Day of the calendar solved by this implementation
+    const DAY: u8;
+
+    /// Puzzle input, once parsed
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parse the puzzle input from `reader`, once it is all read unless the solution can
+    /// parse it as it comes
+    fn read(reader: &mut dyn BufRead) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Hot paths worth benchmarking apart from the parts
+    fn kernels() -> Vec<Kernel<Self::Input>> {
+        vec![]
+    }
+}